@@ -0,0 +1,9 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Generate a test for every case in resource/dayNN.cases.toml
+fn main() -> Result<()> {
+    println!("cargo:rerun-if-changed=resource");
+    let out = PathBuf::from(std::env::var("OUT_DIR")?).join("case_tests.rs");
+    aoc_lib::cases::generate_tests(Path::new("resource"), &out)
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+
+use aoc_lib::common;
+use aoc_lib::graph::{Graph, Names, Visit};
+use aoc_lib::harness::*;
+
+/// All cave data: names, and connections between them
+#[derive(Default, Debug)]
+pub struct CaveSystem {
+    names: Names,
+    tunnels: Graph,
+}
+
+impl CaveSystem {
+    const START: usize = 0;
+    const END: usize = 1;
+
+    /// Parse input text as CaveSystem object
+    fn parse(input: &str) -> CaveSystem {
+        let mut result = CaveSystem::default();
+        result.names.id("start");
+        result.names.id("end");
+        let lines = common::split_lines(input);
+        for line in lines {
+            let parts: Vec<&str> = line.split('-').collect();
+            let (a, b) = (parts[0], parts[1]);
+            let c1 = result.names.id(a);
+            let c2 = result.names.id(b);
+            result.tunnels.add_undirected(c1, c2, 1);
+        }
+        result
+    }
+
+    /// True if the given cave is "big" (has an uppercase name)
+    fn is_big(&self, cave: usize) -> bool {
+        self.names.name(cave).starts_with(|c: char| c.is_uppercase())
+    }
+
+    /// Whether a path can step to the given cave: big caves any number of times, small caves
+    /// once unless it spends a revisit, and never back to the start
+    fn visit(&self, cave: usize, seen: bool) -> Visit {
+        if cave == CaveSystem::START {
+            Visit::Deny
+        } else if self.is_big(cave) || !seen {
+            Visit::Allow
+        } else {
+            Visit::Revisit
+        }
+    }
+
+    /// Number of routes from start to end, allowing the given number of small cave revisits
+    fn count_routes(&self, revisits: usize) -> u64 {
+        self.tunnels.count_paths(CaveSystem::START, CaveSystem::END, revisits, |cave, seen| {
+            self.visit(cave, seen)
+        })
+    }
+}
+
+pub struct Day12;
+
+impl Solution<CaveSystem, u64> for Day12 {
+    fn info(&self) -> SolutionInfo {
+        SolutionInfo::new("Passage Pathing", 2021, 12)
+    }
+
+    fn parse_input(&self, resource: &dyn Resource) -> Result<CaveSystem> {
+        Ok(CaveSystem::parse(&resource.as_str()?))
+    }
+
+    fn solve_part1(&self, input: &CaveSystem) -> Result<u64> {
+        Ok(input.count_routes(0))
+    }
+
+    fn solve_part2(&self, input: &CaveSystem) -> Result<u64> {
+        Ok(input.count_routes(1))
+    }
+}
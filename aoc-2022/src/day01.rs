@@ -0,0 +1,66 @@
+use anyhow::{anyhow, Result};
+use aoc_lib::common;
+use aoc_lib::harness::*;
+
+#[derive(Debug, Clone, Default)]
+pub struct Elf {
+    carried: Vec<u32>,
+}
+
+impl Elf {
+    /// Total calories carried
+    fn total(&self) -> u32 {
+        self.carried.iter().sum()
+    }
+}
+
+type Input = Vec<Elf>;
+type Output = u32;
+pub struct Day01;
+impl Solution<Input, Output> for Day01 {
+    fn info(&self) -> SolutionInfo {
+        SolutionInfo::new("Calorie Counting", 2022, 1)
+    }
+
+    fn parse_input(&self, resource: &dyn Resource) -> Result<Input> {
+        // One paragraph per elf
+        let text = resource.text()?;
+        text.paragraphs()
+            .map(|paragraph| {
+                let carried = common::parse_lines(paragraph, common::parse_str::<u32>)
+                    .map_err(|e| common::at_line(e, common::line_of(&text, paragraph)))?;
+                Ok(Elf { carried })
+            })
+            .collect()
+    }
+
+    fn solve_part1(&self, input: &Input) -> Result<Output> {
+        input.iter().map(|x| x.total()).max().ok_or_else(|| anyhow!("No max"))
+    }
+
+    fn solve_part2(&self, input: &Input) -> Result<Output> {
+        let mut max_first = input.to_vec();
+        max_first.sort_by_key(|x| std::cmp::Reverse(x.total()));
+        Ok(max_first.iter().take(3).map(|x| x.total()).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_lib::common::ParseError;
+
+    #[test]
+    fn test_error_line() {
+        let resource = InlineResource::new("1\n2\n\n3\n4\n5\nx\n");
+        let error = Day01.parse_input(&resource).err().unwrap();
+        assert_eq!(error.downcast_ref::<ParseError>().map(|x| x.line), Some(7));
+    }
+
+    #[test]
+    fn test_parse() {
+        let elves = Day01.parse_input(&InlineResource::new("1\n2\n\n3\n")).unwrap();
+        assert_eq!(elves[0].carried, vec![1, 2]);
+        assert_eq!((elves[0].total(), elves[1].total()), (3, 3));
+    }
+}
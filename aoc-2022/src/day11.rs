@@ -0,0 +1,134 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_lib::common::{self, Captures, Template};
+use aoc_lib::harness::*;
+
+pub struct Day11;
+type Input = Vec<Monkey>;
+type Output = u64;
+impl Solution<Input, Output> for Day11 {
+    fn info(&self) -> SolutionInfo {
+        SolutionInfo::new("Monkey in the Middle", 2022, 11)
+    }
+
+    fn parse_input(&self, resource: &dyn Resource) -> Result<Input> {
+        let text = resource.text()?;
+        text.paragraphs().map(|x| Monkey::parse(x, common::line_of(&text, x))).collect()
+    }
+
+    fn solve_part1(&self, input: &Input) -> Result<Output> {
+        Ok(run_sim(input, true, 20))
+    }
+
+    fn solve_part2(&self, input: &Input) -> Result<Output> {
+        Ok(run_sim(input, false, 10000))
+    }
+}
+
+/// Supported operations: add (fixed value), multiply (fixed value and self, i.e. squaring)
+#[derive(Debug)]
+enum Op {
+    Add(u64),
+    Mult(u64),
+    Sq(),
+}
+
+impl Op {
+    fn parse(op: &str, val: &str) -> Result<Op> {
+        Ok(match (op, val) {
+            ("*", "old") => Op::Sq(),
+            ("*", v) => Op::Mult(v.parse::<u64>()?),
+            ("+", v) => Op::Add(v.parse::<u64>()?),
+            (op, v) => return Err(anyhow!(format!("could not parse op: '{}' '{}'", op, v))),
+        })
+    }
+}
+
+/// Monkey definition
+pub struct Monkey {
+    items: Vec<u64>,
+    op: Op,
+    div_by: u64,
+    true_target: usize,
+    false_target: usize,
+}
+
+/// One template per line of a monkey definition
+const MONKEY: [Template; 6] = [
+    Template::new("Monkey {}:"),
+    Template::new("  Starting items: {}"),
+    Template::new("  Operation: new = old {} {}"),
+    Template::new("  Test: divisible by {}"),
+    Template::new("    If true: throw to monkey {}"),
+    Template::new("    If false: throw to monkey {}"),
+];
+
+impl Monkey {
+    /// Parse a monkey's paragraph, which starts on the given line of the input
+    fn parse(text: &str, line: usize) -> Result<Monkey> {
+        if text.lines().count() != MONKEY.len() {
+            bail!("Expected {} lines for monkey: '{}'", MONKEY.len(), text);
+        }
+        // Errors are relative to a single line of the paragraph
+        let at = |i: usize| move |e| common::at_line(e, line + i);
+        let lines: Vec<Captures> = MONKEY
+            .iter()
+            .zip(text.lines())
+            .enumerate()
+            .map(|(i, (t, l))| t.matches(l).map_err(at(i)))
+            .collect::<Result<_>>()?;
+        Ok(Monkey {
+            items: lines[1].list(0, ", ").map_err(at(1))?,
+            op: Op::parse(lines[2].str(0)?, lines[2].str(1)?).map_err(at(2))?,
+            div_by: lines[3].get(0).map_err(at(3))?,
+            true_target: lines[4].get(0).map_err(at(4))?,
+            false_target: lines[5].get(0).map_err(at(5))?,
+        })
+    }
+}
+
+/// Monkey state as sim runs
+pub struct MonkeyState {
+    items: Vec<u64>,
+    inspections: u64,
+}
+
+/// Run sim for given number of rounds
+fn run_sim(input: &Input, reduce_worry: bool, num_rounds: u32) -> u64 {
+    // Manage "ridiculous" worry levels (part 2) using common multiple
+    let common_multiple: Option<u64> =
+        if reduce_worry { None } else { Some(input.iter().map(|x| x.div_by).product()) };
+    let mut state: Vec<MonkeyState> =
+        input.iter().map(|x| MonkeyState { items: x.items.to_owned(), inspections: 0 }).collect();
+    for _ in 0..num_rounds {
+        run_sim_once(&mut state, input, common_multiple);
+    }
+    let mut inspections: Vec<u64> = state.iter().map(|x| x.inspections).collect();
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    inspections[0] * inspections[1]
+}
+
+/// Run sim for a single round
+fn run_sim_once(state: &mut [MonkeyState], input: &Input, common_multiple: Option<u64>) {
+    for (i, monkey) in input.iter().enumerate() {
+        // This isn't great
+        let b = state[i].items.clone();
+        b.iter().for_each(|x| {
+            let worry = match monkey.op {
+                Op::Add(v) => x + v,
+                Op::Mult(v) => x * v,
+                Op::Sq() => x * x,
+            };
+            let worry = match common_multiple {
+                Some(v) => worry % v,
+                None => worry / 3,
+            };
+            let target = match worry % monkey.div_by == 0 {
+                true => monkey.true_target,
+                false => monkey.false_target,
+            };
+            state[target].items.push(worry);
+        });
+        state[i].inspections += state[i].items.len() as u64;
+        state[i].items.clear();
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Result};
+use aoc_lib::data::{Grid, GridPos, Point2};
+use aoc_lib::harness::*;
+use aoc_lib::path::{self, Path};
+
+pub struct Day12;
+type Input = Area;
+type Output = usize;
+impl Solution<Input, Output> for Day12 {
+    fn info(&self) -> SolutionInfo {
+        SolutionInfo::new("Hill Climbing Algorithm", 2022, 12)
+    }
+
+    fn parse_input(&self, resource: &dyn Resource) -> Result<Input> {
+        let grid = resource.as_u8_grid(std::convert::identity)?;
+        Area::new(grid)
+    }
+
+    fn solve_part1(&self, input: &Input) -> Result<Output> {
+        Ok(path_find(input).ok_or_else(|| anyhow!("No path found"))?.cost)
+    }
+
+    fn solve_part2(&self, input: &Input) -> Result<Output> {
+        Ok(path_find_var(input).ok_or_else(|| anyhow!("No path found"))?.cost)
+    }
+}
+
+/// Track the grid and related start/end points
+pub struct Area {
+    grid: Grid<u8>,
+    start: GridPos,
+    end: GridPos,
+}
+
+impl Area {
+    const START: u8 = b'S';
+    const END: u8 = b'E';
+
+    fn new(grid: Grid<u8>) -> Result<Area> {
+        let (start, _) =
+            grid.find(|x| x == &Area::START).ok_or_else(|| anyhow!("Could not find start"))?;
+        let (end, _) =
+            grid.find(|x| x == &Area::END).ok_or_else(|| anyhow!("Could not find end"))?;
+        Ok(Area { grid, start, end })
+    }
+
+    fn dim(&self) -> &GridPos {
+        self.grid.dim()
+    }
+
+    fn height_at(&self, pos: &GridPos) -> u8 {
+        match *self.grid.get(pos.x, pos.y) {
+            Area::START => b'a',
+            Area::END => b'z',
+            v => v,
+        }
+    }
+}
+
+/// All positions reachable in one step from the given position: at most one higher
+fn neighbours(area: &Area, p: GridPos) -> impl Iterator<Item = GridPos> + '_ {
+    let height = area.height_at(&p);
+    [GridPos::new(-1, 0), GridPos::new(1, 0), GridPos::new(0, -1), GridPos::new(0, 1)]
+        .into_iter()
+        .map(move |step| p + step)
+        .filter(move |n| area.grid.is_in_bounds(n.x, n.y) && area.height_at(n) <= height + 1)
+}
+
+/// Find shortest path from fixed start
+fn path_find(area: &Area) -> Option<Path<GridPos, usize>> {
+    path::bfs(area.start, |p| neighbours(area, *p), |p| p == &area.end)
+}
+
+/// Find shortest path from any starting position at height 'a'
+fn path_find_var(area: &Area) -> Option<Path<GridPos, usize>> {
+    let starts = (0..area.dim().y)
+        .flat_map(|y| (0..area.dim().x).map(move |x| Point2 { x, y }))
+        .filter(|p| area.height_at(p) == b'a');
+    path::bfs_multi(starts, |p| neighbours(area, *p), |p| p == &area.end)
+}
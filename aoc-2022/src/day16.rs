@@ -0,0 +1,193 @@
+use anyhow::Result;
+use aoc_lib::cancel;
+use aoc_lib::common::{self, Template};
+use aoc_lib::graph::{Graph as TunnelGraph, Names};
+use aoc_lib::harness::*;
+use std::collections::HashMap;
+
+pub struct Day16;
+
+type Input = Network;
+type Output = u32;
+
+impl Solution<Input, Output> for Day16 {
+    fn info(&self) -> SolutionInfo {
+        SolutionInfo::new("Proboscidea Volcanium", 2022, 16)
+    }
+
+    fn parse_input(&self, resource: &dyn Resource) -> Result<Input> {
+        let text = resource.text()?;
+        // Get all the valves, make a graph
+        let valves: HashMap<String, Valve> = common::parse_lines(&text, parse_valve)?
+            .into_iter()
+            .map(|valve| (valve.name.to_string(), valve))
+            .collect();
+        // Create graph of direct links between non-zero flow rate valves
+        let is_key = |x: &Valve| x.name == "AA" || x.flow_rate > 0;
+        let keep: Vec<&String> = valves.values().filter(|x| is_key(x)).map(|x| &x.name).collect();
+        let graph = reduce_graph(&valves, &keep);
+        let valves: HashMap<String, Valve> =
+            valves.into_iter().filter(|(_, x)| is_key(x)).collect();
+        Ok(Network { valves, graph })
+    }
+
+    fn solve_part1(&self, input: &Input) -> Result<Output> {
+        Ok(part1(input))
+    }
+
+    fn solve_part2(&self, input: &Input) -> Result<Output> {
+        part2(input)
+    }
+}
+
+const VALVE: Template = Template::new("Valve {} has flow rate={}; tunnels lead to valves {}");
+const VALVE_ONE_TUNNEL: Template =
+    Template::new("Valve {} has flow rate={}; tunnel leads to valve {}");
+
+fn parse_valve(line: &str) -> Result<Valve> {
+    let captures = VALVE.matches(line).or_else(|_| VALVE_ONE_TUNNEL.matches(line))?;
+    Ok(Valve {
+        name: captures.str(0)?.to_string(),
+        flow_rate: captures.get(1)?,
+        tunnels: captures.list(2, ", ")?,
+    })
+}
+
+/// Reduce the tunnels to a graph of just the given valves, with links weighted by the time taken
+/// to get from one to the other and open it
+fn reduce_graph(valves: &HashMap<String, Valve>, nodes_to_keep: &[&String]) -> Graph {
+    let mut names = Names::new();
+    let mut tunnels = TunnelGraph::new(valves.len());
+    for valve in valves.values() {
+        let from = names.id(&valve.name);
+        for tunnel in &valve.tunnels {
+            let to = names.id(tunnel);
+            tunnels.add_edge(from, to, 1);
+        }
+    }
+    let keys: Vec<usize> = nodes_to_keep.iter().map(|x| names.id(x)).collect();
+    let reduced = tunnels.contract(&keys);
+    let mut nodes: HashMap<String, Node> = HashMap::new();
+    for (i, name) in nodes_to_keep.iter().enumerate() {
+        // +1 to include cost of activating the valve
+        let links =
+            reduced.neighbours(i).iter().map(|&(j, cost)| (nodes_to_keep[j].to_string(), cost + 1));
+        nodes.insert(name.to_string(), Node { links: links.collect() });
+    }
+    Graph { nodes }
+}
+
+/// Find the single best path
+fn part1(network: &Network) -> u32 {
+    eval(network, &String::from("AA"), &[], 0, 30, 0, 0, &mut 0)
+}
+
+/// Find the best pair of paths working in tandem
+fn part2(network: &Network) -> Result<u32> {
+    // Our part1 algorithm is just about quick enough to brute force this
+    let num_evals = &mut 0;
+    let mut best = 0;
+    let all: Vec<&String> = network.valves.keys().filter(|x| x != &"AA").collect();
+    // Figure out all the ways we can split the nodes into two lists to tackle
+    // We only need to consider half the space (because it doesn't matter whether it's us or the
+    // elephant visiting the nodes)
+    let count = (1 << all.len()) / 2;
+    for i in 0..count {
+        cancel::check()?;
+        let mut split1 = Vec::new();
+        for (j, valve) in all.iter().enumerate() {
+            if i & (1 << j) >= 1 {
+                split1.push(*valve);
+            }
+        }
+        let mut split2 = all.to_vec();
+        split2.retain(|x| !split1.contains(x));
+        best = (eval(network, &String::from("AA"), &split1, 0, 26, 0, 0, num_evals)
+            + eval(network, &String::from("AA"), &split2, 0, 26, 0, 0, num_evals))
+        .max(best);
+    }
+    Ok(best)
+}
+
+/// Depth-first scan for the "best" score.
+///
+/// Reject any traversals reaching a state that cannot possibly exceed the best score, even under
+/// (impossibly) ideal conditions.
+///
+/// (For "real" input, this gets the search space down from 1+ trillion to about 80k)
+#[allow(clippy::too_many_arguments)]
+fn eval(
+    network: &Network,
+    current: &String,
+    visited: &[&String],
+    tick: u32,
+    max_tick: u32,
+    score: u32,
+    best: u32,
+    num_evals: &mut u32,
+) -> u32 {
+    *num_evals += 1;
+    // Bail out if we have no more time for meaningful action
+    if tick >= max_tick - 2 {
+        return score;
+    }
+    // Bail out of any branches where current + remaining score could not reach best
+    if best > 0 {
+        let mut remain = 0;
+        let mut min_cost = u32::MAX;
+        for (node, cost) in &network.graph.nodes[current].links {
+            if visited.contains(&node) {
+                continue;
+            }
+            remain += network.valves[node].flow_rate;
+            min_cost = min_cost.min(*cost);
+        }
+        if min_cost == u32::MAX
+            || tick + min_cost >= max_tick
+            || score + (max_tick - (tick + min_cost)) * remain <= best
+        {
+            return score;
+        }
+    }
+    let prev = &mut visited.to_vec();
+    prev.push(current);
+    let mut my_best = best.max(score);
+    for (node, cost) in &network.graph.nodes[current].links {
+        // Only hit each node once
+        if prev.contains(&node) {
+            continue;
+        }
+        // Out of time?
+        let tick = tick + cost;
+        if tick >= max_tick {
+            continue;
+        }
+        // Score for this node
+        let score = score + (max_tick - tick) * network.valves[node].flow_rate;
+        my_best = my_best.max(eval(network, node, prev, tick, max_tick, score, my_best, num_evals));
+    }
+    my_best
+}
+
+#[derive(Debug)]
+pub struct Network {
+    valves: HashMap<String, Valve>,
+    graph: Graph,
+}
+
+#[derive(Debug)]
+pub struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    nodes: HashMap<String, Node>,
+}
+
+#[derive(Debug, Default)]
+pub struct Node {
+    links: Vec<(String, u32)>,
+}
@@ -0,0 +1,64 @@
+use aoc_lib::harness::{register, BoxedSolution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// All solutions for 2022, in day order
+pub fn solutions() -> Vec<BoxedSolution> {
+    vec![
+        register(day01::Day01),
+        register(day02::Day02),
+        register(day03::Day03),
+        register(day04::Day04),
+        register(day05::Day05),
+        register(day06::Day06),
+        register(day07::Day07),
+        register(day08::Day08),
+        register(day09::Day09),
+        register(day10::Day10),
+        register(day11::Day11),
+        register(day12::Day12),
+        register(day13::Day13),
+        register(day14::Day14),
+        register(day15::Day15),
+        register(day16::Day16),
+        register(day17::Day17),
+        register(day18::Day18),
+        register(day19::Day19),
+        register(day20::Day20),
+        register(day21::Day21),
+        register(day22::Day22),
+        register(day23::Day23),
+        register(day24::Day24),
+        register(day25::Day25),
+    ]
+}
+
+/// Tests generated from resource/dayNN.cases.toml by build.rs
+#[cfg(test)]
+mod case_tests {
+    include!(concat!(env!("OUT_DIR"), "/case_tests.rs"));
+}
@@ -0,0 +1,191 @@
+use crate::harness::{resource_path, Answer};
+use crate::report::RunReport;
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/// Known answers for a day's puzzle, as recorded in `dayNN.answers` next to the puzzle input.
+///
+/// Each answer is introduced by a `[partN]` header line, and runs until the next header. This
+/// allows for multi-line answers (ASCII art and the like). Surrounding whitespace is ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    parts: BTreeMap<u8, String>,
+}
+
+/// Outcome of checking an answer against the known answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    const SUFFIX: &'static str = "answers";
+
+    /// Parse answers from text
+    pub fn parse(text: &str) -> Result<Answers> {
+        let mut parts = BTreeMap::new();
+        let mut current: Option<(u8, Vec<&str>)> = None;
+        for line in text.lines() {
+            if let Some(part) = line.trim().strip_prefix("[part").and_then(|x| x.strip_suffix(']'))
+            {
+                if let Some((part, lines)) = current.take() {
+                    parts.insert(part, lines.join("\n").trim().to_string());
+                }
+                let part = part.parse().with_context(|| format!("Bad part header: {}", line))?;
+                current = Some((part, Vec::new()));
+            } else if let Some((_, lines)) = current.as_mut() {
+                lines.push(line);
+            } else if !line.trim().is_empty() {
+                bail!("Answer text before first [partN] header: {}", line);
+            }
+        }
+        if let Some((part, lines)) = current {
+            parts.insert(part, lines.join("\n").trim().to_string());
+        }
+        Ok(Answers { parts })
+    }
+
+    /// Take answers from a run of a solution. Parts that failed are left out.
+    pub fn from_report(report: &RunReport) -> Answers {
+        let parts = report
+            .parts
+            .iter()
+            .filter_map(|x| {
+                report.answer(x.part).map(|answer| (x.part, answer.to_string().trim().to_string()))
+            })
+            .collect();
+        Answers { parts }
+    }
+
+    /// Path of the answers file for the given day, which sits alongside its puzzle input
+    pub fn path(year: u32, day: u8) -> Result<PathBuf> {
+        let input = resource_path("", year, day)?;
+        let dir = input.parent().context("Puzzle input has no parent directory")?;
+        Ok(dir.join(format!("day{:02}.{}", day, Answers::SUFFIX)))
+    }
+
+    /// Load known answers for the given day. Ok(None) if none have been recorded.
+    pub fn load(year: u32, day: u8) -> Result<Option<Answers>> {
+        let path = Answers::path(year, day)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read from {}", path.display()))?;
+        Answers::parse(&text)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+            .map(Some)
+    }
+
+    /// Record these answers for the given day
+    pub fn save(&self, year: u32, day: u8) -> Result<PathBuf> {
+        let path = Answers::path(year, day)?;
+        std::fs::write(&path, self.to_string())
+            .with_context(|| format!("Failed to write to {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Known answers, by part
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &str)> {
+        self.parts.iter().map(|(part, x)| (*part, x.as_str()))
+    }
+
+    /// Known answer for given part, if any
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts.get(&part).map(|x| x.as_str())
+    }
+
+    /// Check an answer for given part
+    pub fn check(&self, part: u8, answer: &Answer) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unknown,
+            Some(expected) if answer.matches(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+        }
+    }
+
+    /// Check every part of a run. Parts that failed count as wrong if there's a known answer.
+    pub fn check_report(&self, report: &RunReport) -> Vec<(u8, Verdict)> {
+        report
+            .parts
+            .iter()
+            .map(|x| {
+                let verdict = match (report.answer(x.part), self.get(x.part)) {
+                    (Some(answer), _) => self.check(x.part, answer),
+                    (None, Some(expected)) => Verdict::Fail { expected: expected.to_string() },
+                    (None, None) => Verdict::Unknown,
+                };
+                (x.part, verdict)
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<(u8, String)> for Answers {
+    fn from_iter<T: IntoIterator<Item = (u8, String)>>(iter: T) -> Self {
+        Answers { parts: iter.into_iter().map(|(part, x)| (part, x.trim().to_string())).collect() }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (part, answer) in self.parts.iter() {
+            writeln!(f, "[part{}]", part)?;
+            writeln!(f, "{}", answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("[part1]\n13140\n[part2]\n\n##..\n###.\n").unwrap();
+        assert_eq!(answers.get(1), Some("13140"));
+        assert_eq!(answers.get(2), Some("##..\n###."));
+        assert_eq!(answers.get(3), None);
+    }
+
+    #[test]
+    fn test_parse_bad() {
+        assert!(Answers::parse("13140\n").is_err());
+        assert!(Answers::parse("[partX]\n13140\n").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers::parse("[part1]\n31\n[part2]\n29\n").unwrap();
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[part1]\n31\n").unwrap();
+        assert_eq!(answers.check(1, &Answer::Int(31)), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, &Answer::Int(30)),
+            Verdict::Fail { expected: "31".to_string() }
+        );
+        assert_eq!(answers.check(2, &Answer::Int(29)), Verdict::Unknown);
+        let answers = Answers::parse("[part1]\n1=-0-2\n[part2]\n##..\n#..#\n").unwrap();
+        assert_eq!(answers.check(1, &Answer::from("1=-0-2")), Verdict::Pass);
+        assert_eq!(answers.check(2, &Answer::Art("##..\n#..#\n".to_string())), Verdict::Pass);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::harness::SolutionInfo;
+use anyhow::{bail, Result};
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// How long to keep benchmarking each phase for
+#[derive(Debug, Clone, Copy)]
+pub enum BenchBudget {
+    /// Fixed number of timed runs
+    Runs(usize),
+    /// Keep running until this much time has been spent (at least one run)
+    Time(Duration),
+}
+
+/// Benchmark settings
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring, to warm caches and the like
+    pub warmup: usize,
+    pub budget: BenchBudget,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { warmup: 1, budget: BenchBudget::Runs(10) }
+    }
+}
+
+/// Summary statistics over a number of timed runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculate stats from samples
+    pub fn from_samples(samples: &[Duration]) -> Result<Stats> {
+        if samples.is_empty() {
+            bail!("No samples");
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        // Sample standard deviation; zero if there's nothing to deviate from
+        let variance = if n > 1 {
+            let m = mean.as_secs_f64();
+            sorted.iter().map(|x| (x.as_secs_f64() - m).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Ok(Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Repeatedly time a function according to the given options.
+///
+/// Returns the result of the last run along with timing stats.
+pub fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> Result<T>) -> Result<(T, Stats)> {
+    for _ in 0..options.warmup {
+        f()?;
+    }
+    let mut samples = Vec::new();
+    let started = Instant::now();
+    loop {
+        let time = Instant::now();
+        let result = f()?;
+        samples.push(time.elapsed());
+        let done = match options.budget {
+            BenchBudget::Runs(runs) => samples.len() >= runs,
+            BenchBudget::Time(budget) => started.elapsed() >= budget,
+        };
+        if done {
+            return Ok((result, Stats::from_samples(&samples)?));
+        }
+    }
+}
+
+/// Benchmark results for a solution
+pub struct BenchReport {
+    pub info: SolutionInfo,
+    pub warmup: usize,
+    pub parse: Stats,
+    /// Stats for each part that has an answer, by part number
+    pub parts: Vec<(u8, Stats)>,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let info = &self.info;
+        writeln!(f, "\n--- [{}] Day {}: {} ---", info.year(), info.day(), info.title())?;
+        writeln!(f, "\n{} warm-up run(s)", self.warmup)?;
+        writeln!(
+            f,
+            "{:<12} {:>6} {:>12} {:>12} {:>12} {:>12}",
+            "", "runs", "min", "median", "mean", "stddev"
+        )?;
+        let parts = self.parts.iter().map(|(part, stats)| (format!("Part {}", part), stats));
+        for (name, stats) in std::iter::once(("Parse input".to_string(), &self.parse)).chain(parts)
+        {
+            writeln!(
+                f,
+                "{:<12} {:>6} {:>12} {:>12} {:>12} {:>12}",
+                name,
+                stats.runs,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(vals: &[u64]) -> Vec<Duration> {
+        vals.iter().map(|&x| Duration::from_millis(x)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[4, 2, 8, 6])).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        // sqrt(20 / 3) ms
+        assert_eq!(stats.stddev.as_micros(), 2581);
+    }
+
+    #[test]
+    fn test_stats_single() {
+        let stats = Stats::from_samples(&ms(&[3])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert!(Stats::from_samples(&[]).is_err());
+    }
+
+    #[test]
+    fn test_measure_runs() {
+        let mut calls = 0;
+        let options = BenchOptions { warmup: 2, budget: BenchBudget::Runs(5) };
+        let (result, stats) = measure(&options, || {
+            calls += 1;
+            Ok(calls)
+        })
+        .unwrap();
+        assert_eq!(result, 7);
+        assert_eq!(stats.runs, 5);
+    }
+}
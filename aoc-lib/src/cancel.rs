@@ -0,0 +1,136 @@
+use anyhow::Result;
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Shared flag used to ask a running solution to stop early.
+///
+/// Cancellation is cooperative: long-running solutions should call `cancel::check()?` in their hot
+/// loops, which fails with `Cancelled` once the harness has given up on the current part.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Request cancellation
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// True if cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fail with Cancelled if cancellation has been requested
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(Cancelled.into());
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by a solution that stopped because it was cancelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+thread_local! {
+    /// Token for whatever the harness is running on this thread, if anything
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Fail with Cancelled if the harness has cancelled the part running on this thread.
+///
+/// Only sees the token for the calling thread, so solutions that spawn their own threads need to
+/// call this from the original thread.
+pub fn check() -> Result<()> {
+    CURRENT.with(|x| x.borrow().as_ref().map(|token| token.check()).unwrap_or(Ok(())))
+}
+
+/// Run f with the given token installed as the current thread's token
+pub fn with_token<T>(token: &CancelToken, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|x| x.replace(Some(token.clone())));
+    // Restore the previous token even if f panics
+    struct Restore(Option<CancelToken>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|x| *x.borrow_mut() = previous);
+        }
+    }
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Run f, cancelling its token if it's still going after the given time limit.
+///
+/// The token is installed as the current thread's token, so that `check()` sees it.
+pub fn with_time_limit<T>(limit: Duration, f: impl FnOnce() -> T) -> T {
+    let token = CancelToken::new();
+    let (done, watchdog_done) = mpsc::channel::<()>();
+    let watchdog_token = token.clone();
+    let watchdog = thread::spawn(move || {
+        if let Err(mpsc::RecvTimeoutError::Timeout) = watchdog_done.recv_timeout(limit) {
+            watchdog_token.cancel();
+        }
+    });
+    let result = with_token(&token, f);
+    // Wake the watchdog so it doesn't hang around for the rest of the limit
+    drop(done);
+    let _ = watchdog.join();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn test_no_token() {
+        assert!(check().is_ok());
+    }
+
+    #[test]
+    fn test_with_token() {
+        let token = CancelToken::new();
+        with_token(&token, || {
+            assert!(check().is_ok());
+            token.cancel();
+            let err = check().unwrap_err();
+            assert_eq!(err.downcast_ref::<Cancelled>(), Some(&Cancelled));
+        });
+        // Token is only current within with_token
+        assert!(check().is_ok());
+    }
+
+    #[test]
+    fn test_time_limit() {
+        let time = Instant::now();
+        let result: Result<()> = with_time_limit(Duration::from_millis(20), || loop {
+            check()?;
+            thread::sleep(Duration::from_millis(1));
+        });
+        assert!(result.unwrap_err().is::<Cancelled>());
+        assert!(time.elapsed() < Duration::from_secs(5));
+        // Quick enough to finish in time
+        let result: Result<u32> = with_time_limit(Duration::from_secs(5), || Ok(1));
+        assert_eq!(result.unwrap(), 1);
+    }
+}
@@ -0,0 +1,411 @@
+use crate::answers::{Answers, Verdict};
+use crate::common::{in_resource, str_to_u8, ParseError};
+use crate::harness::{
+    inline_name, try_solve, Answer, DynSolution, Resource, ResourceText, RunLimits, Solution,
+};
+use crate::input;
+use crate::report::RunReport;
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use toml::Spanned;
+
+/// Named inputs for a day, each with its expected answers, as listed in `dayNN.cases.toml`.
+///
+/// Manifests are looked for wherever puzzle inputs are (see `input`), and the cases from every
+/// manifest found are run, so extra inputs can be added from outside the repo.
+///
+/// Manifests are TOML: each case is a `[[case]]` table with an optional `name`, either a `file`
+/// (relative to the manifest) or inline `text`, and expected answers as `part1`, `part2` and so
+/// on, each an integer or a string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cases {
+    pub cases: Vec<Case>,
+}
+
+/// A single named input and its expected answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub name: String,
+    pub input: CaseInput,
+    pub expected: Answers,
+}
+
+/// Where a case's input comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaseInput {
+    File(PathBuf),
+    Text(String),
+}
+
+/// Manifest as written, before files are resolved and cases checked
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Manifest {
+    #[serde(default)]
+    case: Vec<Spanned<RawCase>>,
+}
+
+/// Case as written in a manifest
+#[derive(Deserialize)]
+struct RawCase {
+    name: Option<String>,
+    file: Option<String>,
+    text: Option<String>,
+    /// Expected answers, keyed `part1`, `part2` and so on
+    #[serde(flatten)]
+    answers: BTreeMap<String, toml::Value>,
+}
+
+impl RawCase {
+    fn finish(self, dir: &Path) -> Result<Case> {
+        let input = match (self.file, self.text) {
+            (Some(file), None) => CaseInput::File(dir.join(file)),
+            (None, Some(text)) => CaseInput::Text(text),
+            (Some(_), Some(_)) => bail!("Case has both file and text"),
+            (None, None) => bail!("Case has neither file nor text"),
+        };
+        let name = match (self.name, &input) {
+            (Some(name), _) => name,
+            (None, CaseInput::File(path)) => {
+                path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or_default()
+            }
+            (None, CaseInput::Text(text)) => text.lines().next().unwrap_or_default().to_string(),
+        };
+        let mut expected = Vec::new();
+        for (key, value) in self.answers {
+            let part = match key.strip_prefix("part").and_then(|x| x.parse::<u8>().ok()) {
+                Some(part) => part,
+                None => bail!("Unrecognised key '{}'", key),
+            };
+            let answer = match value {
+                toml::Value::Integer(x) => x.to_string(),
+                toml::Value::String(x) => x,
+                x => bail!("Expected an integer or string for {}, not {}", key, x.type_str()),
+            };
+            expected.push((part, answer));
+        }
+        Ok(Case { name, input, expected: expected.into_iter().collect() })
+    }
+}
+
+impl Cases {
+    const SUFFIX: &'static str = "cases.toml";
+
+    /// Parse manifest text, with files relative to given dir. Errors are ParseErrors, pointing at
+    /// the bad TOML or the case at fault.
+    pub fn parse(text: &str, dir: &Path) -> Result<Cases> {
+        let manifest: Manifest = toml::from_str(text).map_err(|e| {
+            let span = e.span().unwrap_or(0..0);
+            ParseError::new(text, span.start, span.len(), e.message())
+        })?;
+        let cases = manifest
+            .case
+            .into_iter()
+            .map(|case| {
+                let span = case.span();
+                case.into_inner().finish(dir).map_err(|e| {
+                    ParseError::new(text, span.start, span.len(), format!("{:#}", e)).into()
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Cases { cases })
+    }
+
+    /// Read and parse the manifest at given path, naming it in any error
+    fn read(path: &Path) -> Result<Cases> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read from {}", path.display()))?;
+        Cases::parse(&text, path.parent().unwrap_or(Path::new(".")))
+            .map_err(|e| in_resource(e, || path.display().to_string()))
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Load and merge the cases from every manifest for given day. Empty if there are none.
+    pub fn load(year: u32, day: u8) -> Result<Cases> {
+        let mut result = Cases::default();
+        for path in input::candidates(&input::input_dirs()?, Cases::SUFFIX, year, day) {
+            if path.is_file() {
+                result.cases.extend(Cases::read(&path)?.cases);
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl CaseInput {
+    /// Input text for the case
+    pub fn load(&self) -> Result<String> {
+        match self {
+            CaseInput::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read from {}", path.display())),
+            CaseInput::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+/// Case input, once loaded
+struct CaseResource {
+    name: String,
+    text: String,
+}
+
+impl CaseResource {
+    fn load(case: &Case) -> Result<CaseResource> {
+        let name = match &case.input {
+            CaseInput::File(path) => format!("case '{}' ({})", case.name, path.display()),
+            CaseInput::Text(text) => format!("case '{}' ({})", case.name, inline_name(text)),
+        };
+        Ok(CaseResource { name, text: case.input.load()? })
+    }
+}
+
+impl Resource for CaseResource {
+    fn as_str(&self) -> Result<String> {
+        Ok(self.text.clone())
+    }
+
+    fn as_u8(&self) -> Result<Vec<u8>> {
+        Ok(str_to_u8(&self.text))
+    }
+
+    fn text(&self) -> Result<ResourceText<'_>> {
+        Ok(ResourceText::new(self.text.as_str()))
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Results of running a single case
+#[derive(Debug, Clone)]
+pub struct CaseReport {
+    pub name: String,
+    pub report: RunReport,
+    pub verdicts: Vec<(u8, Verdict)>,
+}
+
+impl CaseReport {
+    /// True if nothing failed and no answer was wrong
+    pub fn passed(&self) -> bool {
+        self.report.to_result().is_ok()
+            && !self.verdicts.iter().any(|(_, x)| matches!(x, Verdict::Fail { .. }))
+    }
+}
+
+impl Display for CaseReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let info = &self.report.info;
+        write!(f, "[{}] Day {:02} {}:", info.year(), info.day(), self.name)?;
+        if let Some(error) = &self.report.error {
+            return write!(f, " Error: {}", error);
+        }
+        let parts: Vec<String> = self
+            .report
+            .parts
+            .iter()
+            .zip(self.verdicts.iter())
+            .map(|(part, (_, verdict))| match self.report.answer(part.part) {
+                Some(_) => format!("Part {} {}", part.part, verdict),
+                None => format!("Part {} {}", part.part, part.outcome),
+            })
+            .collect();
+        write!(f, " {}", parts.join(", "))
+    }
+}
+
+/// Run a solution on every case, checking its answers
+pub fn run_cases(
+    solution: &dyn DynSolution,
+    cases: &Cases,
+    limits: &RunLimits,
+) -> Result<Vec<CaseReport>> {
+    cases
+        .cases
+        .iter()
+        .map(|case| {
+            let report = solution.run(&CaseResource::load(case)?, limits);
+            let verdicts = case.expected.check_report(&report);
+            Ok(CaseReport { name: case.name.clone(), report, verdicts })
+        })
+        .collect()
+}
+
+/// Run one part of a case from given manifest, failing unless it gives the expected answer.
+///
+/// This is what generated tests (see `generate_tests`) call. Only the given part is solved, so
+/// slow parts don't hold up tests of the others.
+pub fn test_case<S, I, O>(solution: &S, manifest: &str, index: usize, part: u8) -> Result<()>
+where
+    S: Solution<I, O>,
+    O: Into<Answer>,
+{
+    let manifest = Path::new(manifest);
+    let cases = Cases::read(manifest)?;
+    let case = cases
+        .cases
+        .get(index)
+        .ok_or_else(|| anyhow!("No case {} in {}", index, manifest.display()))?;
+    let expected =
+        case.expected.get(part).ok_or_else(|| anyhow!("No part {} answer", part))?.to_string();
+    let resource = CaseResource::load(case)?;
+    let answer: Answer = try_solve(solution, part.into(), &resource)?.into();
+    if !answer.matches(&expected) {
+        bail!(
+            "Wrong answer for part {} on {}\n  expected: {}\n    actual: {}",
+            part,
+            resource.name(),
+            expected,
+            answer
+        );
+    }
+    Ok(())
+}
+
+/// Write tests for every case in the manifests in given resource dir to the out file, for use in
+/// a build script. Each `dayNN.cases.toml` gives one test per (case, part with an answer), named
+/// after the day, case and part, which calls `test_case` on `crate::dayNN::DayNN`.
+pub fn generate_tests(resource_dir: &Path, out: &Path) -> Result<()> {
+    let mut manifests = Vec::new();
+    for entry in std::fs::read_dir(resource_dir)
+        .with_context(|| format!("Failed to read {}", resource_dir.display()))?
+    {
+        let path = entry?.path();
+        let name = path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or_default();
+        if let Some(day) = name.strip_prefix("day").and_then(|x| x.strip_suffix(".cases.toml")) {
+            let day: u8 = day.parse().with_context(|| format!("Bad manifest name: {}", name))?;
+            manifests.push((day, path));
+        }
+    }
+    manifests.sort();
+    let mut result = String::new();
+    let mut names = HashSet::new();
+    for (day, path) in manifests {
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("Failed to canonicalize path: {}", path.display()))?;
+        let cases = Cases::read(&path)?;
+        for (index, case) in cases.cases.iter().enumerate() {
+            for (part, _) in case.expected.iter() {
+                // Keep test names unique, even if case names aren't
+                let base = format!("day{:02}_{}_part{}", day, identifier(&case.name), part);
+                let mut name = base.clone();
+                for i in 2.. {
+                    if names.insert(name.clone()) {
+                        break;
+                    }
+                    name = format!("{}_{}", base, i);
+                }
+                result += &format!(
+                    "#[test]\nfn {}() -> anyhow::Result<()> {{\n    \
+                    aoc_lib::cases::test_case(&crate::day{:02}::Day{:02}, {:?}, {}, {})\n}}\n\n",
+                    name,
+                    day,
+                    day,
+                    path.to_string_lossy(),
+                    index,
+                    part
+                );
+            }
+        }
+    }
+    std::fs::write(out, result).with_context(|| format!("Failed to write to {}", out.display()))
+}
+
+/// Case name squashed into something usable in a function name
+fn identifier(name: &str) -> String {
+    let mut result = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            result.push(c.to_ascii_lowercase());
+        } else if !result.ends_with('_') {
+            result.push('_');
+        }
+    }
+    result.trim_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+# Examples from the puzzle
+[[case]]
+name = "small"
+file = "day09.test.1"
+part1 = 13
+part2 = 1
+
+[[case]]
+text = """
+R 5
+U 8"""
+part2 = "1_000"
+"#;
+
+    #[test]
+    fn test_parse() {
+        let cases = Cases::parse(MANIFEST, Path::new("/inputs")).unwrap().cases;
+        assert_eq!(cases.len(), 2);
+        assert_eq!(cases[0].name, "small");
+        assert_eq!(cases[0].input, CaseInput::File(PathBuf::from("/inputs/day09.test.1")));
+        assert_eq!(cases[0].expected.get(1), Some("13"));
+        assert_eq!(cases[0].expected.get(2), Some("1"));
+        assert_eq!(cases[1].name, "R 5");
+        assert_eq!(cases[1].input, CaseInput::Text("R 5\nU 8".to_string()));
+        assert_eq!(cases[1].expected.get(1), None);
+        assert_eq!(cases[1].expected.get(2), Some("1_000"));
+    }
+
+    #[test]
+    fn test_parse_bad() {
+        let dir = Path::new(".");
+        assert!(Cases::parse("part1 = 1", dir).is_err());
+        assert!(Cases::parse("[[case]]\npart1 = 1", dir).is_err());
+        assert!(Cases::parse("[[case]]\nfile = \"x\npart1 = 1", dir).is_err());
+        assert!(Cases::parse("[[case]]\ntext = \"\"\"\nabc", dir).is_err());
+        assert!(Cases::parse("[[case]]\ntext = \"x\"\npart1 = 1x", dir).is_err());
+        assert!(Cases::parse("[[case]]\ntext = \"x\"\nanswer = 1", dir).is_err());
+        assert!(Cases::parse("[[case]]\ntext = \"x\"\nfile = \"y\"", dir).is_err());
+        assert!(Cases::parse("[[case]]\ntext = \"x\"\npart1 = 1.5", dir).is_err());
+        let error = Cases::parse("[[case]]\nfile = \"x\"\n\n[[case]]\npart1 = 1\n", dir);
+        let error = error.unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.message.as_str()), (4, "Case has neither file nor text"));
+        let error = Cases::parse("[[case]]\ntext = \"x\"\npart1 = 1x\n", dir).unwrap_err();
+        assert_eq!(error.downcast::<ParseError>().unwrap().line, 3);
+    }
+
+    #[test]
+    fn test_parse_toml() {
+        let manifest =
+            "[[case]] # first\ntext = 'C:\\x' # literal\npart1 = \"\"\"4\"\"\"  # done\n";
+        let cases = Cases::parse(manifest, Path::new(".")).unwrap().cases;
+        assert_eq!(cases[0].input, CaseInput::Text("C:\\x".to_string()));
+        assert_eq!(cases[0].expected.get(1), Some("4"));
+        assert_eq!(Cases::parse("", Path::new(".")).unwrap().cases.len(), 0);
+    }
+
+    #[test]
+    fn test_generate_tests_error() {
+        let dir = std::env::temp_dir().join(format!("aoc-cases-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day03.cases.toml"), "[[case]]\ntext = \"x\"\npart1 = [1]\n")
+            .unwrap();
+        let error = generate_tests(&dir, &dir.join("out.rs")).unwrap_err();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let error = format!("{:#}", error);
+        assert!(error.contains("day03.cases.toml:1:1\n"), "{}", error);
+        assert!(error.contains("Expected an integer or string for part1"), "{}", error);
+    }
+
+    #[test]
+    fn test_identifier() {
+        assert_eq!(identifier("larger example"), "larger_example");
+        assert_eq!(identifier("1,1,1 - 2,1,1"), "1_1_1_2_1_1");
+        assert_eq!(identifier("8A004A801A8002F478"), "8a004a801a8002f478");
+    }
+}
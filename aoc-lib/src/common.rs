@@ -1,4 +1,5 @@
-use anyhow::{anyhow, bail, Result};
+use anyhow::{anyhow, Error, Result};
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 /// Convert string to Vec of u8
@@ -26,22 +27,450 @@ pub fn split_lines_keep_empty(content: &str) -> Vec<&str> {
     split_lines_ext(content, true)
 }
 
-/// Parse a value, standardising into Result
-pub fn parse_str<T: FromStr>(val: &str) -> Result<T> {
-    match val.trim().parse::<T>() {
-        Ok(v) => Ok(v),
-        Err(_) => bail!("Could not parse '{}'", val),
+/// Failure to parse some input, with where it happened.
+///
+/// Displays as the message followed by a snippet of the offending line with the bad text marked,
+/// e.g.
+///
+/// ```text
+/// Could not parse 'x'
+///  --> day01.test:2:3
+///   |
+/// 2 | 1 x 3
+///   |   ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// Name of the resource being parsed, once known
+    pub resource: Option<String>,
+    /// Line number, from 1
+    pub line: usize,
+    /// Column in chars, from 1
+    pub column: usize,
+    /// Length of the offending text in chars
+    pub len: usize,
+    /// The whole offending line
+    pub text: String,
+}
+
+impl ParseError {
+    /// Error for the text at given byte offset and length into text, which may span many lines
+    pub fn new(text: &str, offset: usize, len: usize, message: impl Into<String>) -> ParseError {
+        let start = text[..offset].rfind('\n').map(|x| x + 1).unwrap_or(0);
+        let end = text[offset..].find('\n').map(|x| offset + x).unwrap_or(text.len());
+        let line = &text[start..end];
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let len = text[offset..(offset + len).min(start + line.len()).max(offset)].chars().count();
+        ParseError {
+            message: message.into(),
+            resource: None,
+            line: text[..start].matches('\n').count() + 1,
+            column: column(line, (offset - start).min(line.len())),
+            len,
+            text: line.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.message)?;
+        match &self.resource {
+            Some(resource) => writeln!(f, " --> {}:{}:{}", resource, self.line, self.column)?,
+            None => writeln!(f, " --> line {}, column {}", self.line, self.column)?,
+        }
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), "^".repeat(self.len.max(1)))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Shift a ParseError from some text down to the given line of a larger text. Other errors are
+/// given the line number as context.
+pub fn at_line(mut error: Error, line: usize) -> Error {
+    if let Some(parse_error) = error.downcast_mut::<ParseError>() {
+        parse_error.line += line - 1;
+        return error;
     }
+    error.context(format!("Line {}", line))
+}
+
+/// Line number (from 1) in text on which part starts, for passing to at_line. Part must borrow
+/// from text, e.g. a paragraph of it.
+pub fn line_of(text: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(text.as_ptr() as usize).min(text.len());
+    text[..offset].matches('\n').count() + 1
+}
+
+/// Name the resource a ParseError came from, if it doesn't have one yet. Only meaningful if the
+/// error's line counts from the start of the resource, so errors from parsing part of the input
+/// should be shifted with at_line first.
+pub fn in_resource(mut error: Error, resource: impl FnOnce() -> String) -> Error {
+    if let Some(parse_error) = error.downcast_mut::<ParseError>() {
+        parse_error.resource.get_or_insert_with(resource);
+    }
+    error
+}
+
+/// Parse a value, standardising into Result. Surrounding whitespace is ignored.
+pub fn parse_str<T: FromStr>(val: &str) -> Result<T> {
+    let trimmed = val.trim_start();
+    parse_at(val, val.len() - trimmed.len(), trimmed.trim_end())
 }
 
 /// Split text on given delim, converting tokens with parse()
 ///
 /// Empty tokens will be ignored.
 pub fn tokenize<T: FromStr>(text: &str, delim: char) -> Result<Vec<T>> {
-    text.split(delim).filter(|x| !x.is_empty()).map(|x| parse_str(x)).collect()
+    tokenize_at(text, 0, text.len(), delim)
+}
+
+/// As tokenize, for the part of text in the given byte range
+fn tokenize_at<T: FromStr>(text: &str, start: usize, end: usize, delim: char) -> Result<Vec<T>> {
+    let mut result = Vec::new();
+    let mut offset = start;
+    for token in text[start..end].split(delim) {
+        if !token.is_empty() {
+            let trimmed = token.trim_start();
+            result.push(parse_at(text, offset + token.len() - trimmed.len(), trimmed.trim_end())?);
+        }
+        offset += token.len() + delim.len_utf8();
+    }
+    Ok(result)
 }
 
 /// Split the first line of given text, converting tokens with parse()
 pub fn tokenize_first_line<T: FromStr>(content: &str, delim: char) -> Result<Vec<T>> {
-    tokenize(split_lines(content).first().ok_or_else(|| anyhow!("No data"))?, delim)
+    let first = split_lines(content).into_iter().next().ok_or_else(|| anyhow!("No data"))?;
+    // Offset of the first line, to point errors at the right place in content
+    let start = first.as_ptr() as usize - content.as_ptr() as usize;
+    tokenize_at(content, start, start + first.len(), delim)
+}
+
+/// Lines of text without their line endings (`\n` or `\r\n`), skipping empty lines
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().filter(|x| !x.is_empty())
+}
+
+/// Blocks of lines separated by one or more blank lines.
+///
+/// Each paragraph borrows from the text, and keeps its inner line endings but not trailing
+/// whitespace. Lines holding only whitespace count as blank.
+pub fn paragraphs(text: &str) -> impl Iterator<Item = &str> {
+    let mut offset = 0;
+    let mut lines = text
+        .split_inclusive('\n')
+        .map(move |line| {
+            let start = offset;
+            offset += line.len();
+            (start, line)
+        })
+        .peekable();
+    let is_blank = |(_, line): &(usize, &str)| line.trim().is_empty();
+    std::iter::from_fn(move || {
+        while lines.next_if(is_blank).is_some() {}
+        let (start, line) = lines.next()?;
+        let mut end = start + line.trim_end().len();
+        while let Some((i, line)) = lines.next_if(|x| !is_blank(x)) {
+            end = i + line.trim_end().len();
+        }
+        Some(&text[start..end])
+    })
+}
+
+/// Records split on given delimiter, trimmed of surrounding whitespace. Empty records are skipped.
+pub fn records<'a>(text: &'a str, delim: &'a str) -> impl Iterator<Item = &'a str> {
+    text.split(delim).map(|x| x.trim()).filter(|x| !x.is_empty())
+}
+
+/// Column (1-based, in chars) at which the given byte offset into line falls
+fn column(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+/// Parse a token found at given byte offset into text, failing with its location
+fn parse_at<T: FromStr>(text: &str, offset: usize, token: &str) -> Result<T> {
+    match token.parse::<T>() {
+        Ok(v) => Ok(v),
+        Err(_) => {
+            let message = format!("Could not parse '{}'", token);
+            Err(ParseError::new(text, offset, token.len(), message).into())
+        }
+    }
+}
+
+/// Every integer in text, each with an optional leading minus sign.
+///
+/// e.g. `[2, 18, -2, 15]` from "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+pub fn ints<T: FromStr>(text: &str) -> Result<Vec<T>> {
+    let bytes = text.as_bytes();
+    let digits_from = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let digits = if bytes[i] == b'-' { i + 1 } else { i };
+        i = digits_from(digits);
+        if i > digits {
+            result.push(parse_at(text, start, &text[start..i])?);
+        } else {
+            i = start + 1;
+        }
+    }
+    Ok(result)
+}
+
+/// Split text on given delimiter, parsing each trimmed item
+pub fn list<T: FromStr>(text: &str, delim: &str) -> Result<Vec<T>> {
+    list_at(text, 0, text, delim)
+}
+
+/// As list, for text found at given byte offset into line
+fn list_at<T: FromStr>(line: &str, offset: usize, text: &str, delim: &str) -> Result<Vec<T>> {
+    let mut result = Vec::new();
+    let mut start = offset;
+    for item in text.split(delim) {
+        let trimmed = item.trim_start();
+        let item_offset = start + item.len() - trimmed.len();
+        result.push(parse_at(line, item_offset, trimmed.trim_end())?);
+        start += item.len() + delim.len();
+    }
+    Ok(result)
+}
+
+/// Literal text with `{}` placeholders, matched against a line to pull out the placeholders'
+/// values, e.g. `"Valve {} has flow rate={}; tunnels lead to valves {}"`.
+///
+/// Each placeholder takes the shortest text that lets the following literal match, except the
+/// last, which runs to the end of the line (less any trailing literal).
+#[derive(Debug, Clone, Copy)]
+pub struct Template<'t> {
+    pattern: &'t str,
+}
+
+impl<'t> Template<'t> {
+    pub const fn new(pattern: &'t str) -> Self {
+        Self { pattern }
+    }
+
+    /// Match line against template, failing with the column where they differ
+    pub fn matches<'a>(&self, line: &'a str) -> Result<Captures<'a>> {
+        let literals: Vec<&str> = self.pattern.split("{}").collect();
+        let expect = |offset: usize, literal: &str| {
+            let message = format!("Expected '{}'", literal);
+            anyhow::Error::from(ParseError::new(line, offset, literal.len(), message))
+        };
+        if !line.starts_with(literals[0]) {
+            return Err(expect(0, literals[0]));
+        }
+        let mut offset = literals[0].len();
+        let mut fields = Vec::new();
+        for (i, &literal) in literals.iter().enumerate().skip(1) {
+            let rest = &line[offset..];
+            let len = if i == literals.len() - 1 {
+                rest.strip_suffix(literal).map(|x| x.len())
+            } else {
+                rest.find(literal)
+            };
+            let len = len.ok_or_else(|| expect(offset, literal))?;
+            fields.push((offset, &rest[..len]));
+            offset += len + literal.len();
+        }
+        Ok(Captures { line, fields })
+    }
+}
+
+/// Values of a template's placeholders, as matched against a line
+#[derive(Debug, Clone)]
+pub struct Captures<'a> {
+    line: &'a str,
+    /// Byte offset into line and text of each placeholder
+    fields: Vec<(usize, &'a str)>,
+}
+
+impl<'a> Captures<'a> {
+    /// Number of placeholders
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Text of the given placeholder
+    pub fn str(&self, index: usize) -> Result<&'a str> {
+        self.field(index).map(|(_, text)| text)
+    }
+
+    /// Parse the given placeholder
+    pub fn get<T: FromStr>(&self, index: usize) -> Result<T> {
+        let (offset, text) = self.field(index)?;
+        parse_at(self.line, offset, text)
+    }
+
+    /// Split the given placeholder on a delimiter, parsing each trimmed item
+    pub fn list<T: FromStr>(&self, index: usize, delim: &str) -> Result<Vec<T>> {
+        let (offset, text) = self.field(index)?;
+        list_at(self.line, offset, text, delim)
+    }
+
+    /// Offset and text of the given placeholder, failing if there's no such placeholder
+    fn field(&self, index: usize) -> Result<(usize, &'a str)> {
+        self.fields.get(index).copied().ok_or_else(|| {
+            anyhow!("No placeholder {} in template: only {} captured", index, self.fields.len())
+        })
+    }
+}
+
+/// Parse each non-empty line of text, failing with the line number of the first that won't parse
+pub fn parse_lines<T>(text: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| f(line).map_err(|e| at_line(e, i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Debug;
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines("a\r\nb\n\nc").collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(lines("").count(), 0);
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let text = "\n1\n2\n\n\n3\r\n4 \r\n  \r\n5\n";
+        assert_eq!(paragraphs(text).collect::<Vec<_>>(), vec!["1\n2", "3\r\n4", "5"]);
+        assert_eq!(paragraphs("  1\n").collect::<Vec<_>>(), vec!["  1"]);
+        assert_eq!(paragraphs("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_records() {
+        assert_eq!(records("a, b,,c\n", ",").collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(records("x -> y", "->").collect::<Vec<_>>(), vec!["x", "y"]);
+    }
+
+    /// Line, column and length of a ParseError
+    fn location<T: Debug>(result: Result<T>) -> (usize, usize, usize) {
+        let error = result.unwrap_err();
+        let error = error.downcast_ref::<ParseError>().expect("should be a ParseError");
+        (error.line, error.column, error.len)
+    }
+
+    #[test]
+    fn test_ints() {
+        let line = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+        assert_eq!(ints::<i32>(line).unwrap(), vec![2, 18, -2, 15]);
+        assert_eq!(ints::<i32>("a-b--3 4-5").unwrap(), vec![-3, 4, -5]);
+        assert!(ints::<i32>("none").unwrap().is_empty());
+        assert_eq!(location(ints::<u8>("1, 2, 300")), (1, 7, 3));
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(list::<u32>("1, 2,3", ",").unwrap(), vec![1, 2, 3]);
+        assert_eq!(list::<String>("AA", ", ").unwrap(), vec!["AA"]);
+        assert_eq!(location(list::<u32>("1, x", ",")), (1, 4, 1));
+    }
+
+    #[test]
+    fn test_template() -> Result<()> {
+        const VALVE: Template =
+            Template::new("Valve {} has flow rate={}; tunnels lead to valves {}");
+        let captures = VALVE.matches("Valve AA has flow rate=0; tunnels lead to valves DD, II")?;
+        assert_eq!(captures.len(), 3);
+        assert_eq!(captures.str(0)?, "AA");
+        assert_eq!(captures.get::<u32>(1)?, 0);
+        assert_eq!(captures.list::<String>(2, ", ")?, vec!["DD", "II"]);
+        let captures = Template::new("({},{})").matches("(1,-2)")?;
+        assert_eq!((captures.get::<i32>(0)?, captures.get::<i32>(1)?), (1, -2));
+        let result = VALVE.matches("Valve BB has flow rate=x; tunnels lead to valves AA")?.get(1);
+        assert_eq!(location::<u32>(result), (1, 24, 1));
+        let error =
+            VALVE.matches("Valve BB has flow rate=1; tunnel leads to valve AA").unwrap_err();
+        assert!(error.to_string().starts_with("Expected '; tunnels lead to valves '\n"));
+        assert!(VALVE.matches("Tunnel AA").is_err());
+        let captures = Template::new("({},{})").matches("(1,2)")?;
+        let error = captures.get::<u32>(2).unwrap_err();
+        assert_eq!(error.to_string(), "No placeholder 2 in template: only 2 captured");
+        assert!(captures.str(5).is_err() && captures.list::<u32>(2, ",").is_err());
+        assert!(Template::new("({},{})").matches("(1,2").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n\n2\n", parse_str::<u32>).unwrap(), vec![1, 2]);
+        assert_eq!(location(parse_lines("1\n\n x\n", parse_str::<u32>)), (3, 2, 1));
+        let error = parse_lines("1\n\nx\n", |_| Err::<u32, _>(anyhow!("Bad"))).unwrap_err();
+        assert_eq!(format!("{:#}", error), "Line 1: Bad");
+    }
+
+    #[test]
+    fn test_parse_str() {
+        assert_eq!(parse_str::<i32>(" -4\n").unwrap(), -4);
+        assert_eq!(location(parse_str::<i32>("  4x ")), (1, 3, 2));
+        assert_eq!(tokenize::<u32>("1,,2", ',').unwrap(), vec![1, 2]);
+        assert_eq!(location(tokenize::<u32>("1,,2, ab", ',')), (1, 7, 2));
+        assert_eq!(tokenize_first_line::<u32>("\n4 5\n6", ' ').unwrap(), vec![4, 5]);
+        assert_eq!(location(tokenize_first_line::<u32>("\n4 x\n6", ' ')), (2, 3, 1));
+        assert!(tokenize_first_line::<u32>("\n", ' ').is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let mut error = ParseError::new("1 2\n3 xyz 4\r\n5", 6, 3, "Could not parse 'xyz'");
+        assert_eq!((error.line, error.column, error.len), (2, 3, 3));
+        assert_eq!(
+            error.to_string(),
+            "Could not parse 'xyz'\n --> line 2, column 3\n  |\n2 | 3 xyz 4\n  |   ^^^"
+        );
+        error.resource = Some("day01.test".to_string());
+        error.line = 10;
+        assert_eq!(
+            error.to_string(),
+            "Could not parse 'xyz'\n --> day01.test:10:3\n   |\n10 | 3 xyz 4\n   |   ^^^"
+        );
+        let error = in_resource(at_line(error.into(), 3), || "other".to_string());
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.resource.as_deref(), error.line), (Some("day01.test"), 12));
+    }
+
+    #[test]
+    fn test_line_of() {
+        let text = "1\n2\n\n3\r\nx\n";
+        let parts: Vec<&str> = paragraphs(text).collect();
+        assert_eq!((line_of(text, parts[0]), line_of(text, parts[1])), (1, 4));
+        assert_eq!(line_of(text, lines(parts[1]).nth(1).unwrap()), 5);
+        let error = parse_lines(parts[1], parse_str::<u32>).unwrap_err();
+        assert_eq!(location(Err::<(), _>(at_line(error, line_of(text, parts[1])))), (5, 1, 1));
+    }
+
+    #[test]
+    fn test_parse_error_crlf() {
+        let error = ParseError::new("1\r\n\r\n3", 3, 1, "Could not parse ''");
+        assert_eq!((error.line, error.column, error.len, error.text.as_str()), (2, 1, 0, ""));
+        assert_eq!(location(parse_str::<u32>("\r")), (1, 1, 0));
+        assert_eq!(location(parse_str::<u32>("  \r")), (1, 3, 0));
+        assert_eq!(location(tokenize::<u32>("1 2 \r", ' ')), (1, 5, 0));
+        assert_eq!(location(tokenize::<u32>("1\r\n2\r\n\r\n3\r\n", '\n')), (3, 1, 0));
+        assert_eq!(location(tokenize_first_line::<u32>("1,\r\n2", ',')), (1, 3, 0));
+        assert_eq!(location(list::<u32>("1, 2,\r", ",")), (1, 6, 0));
+    }
 }
@@ -0,0 +1,343 @@
+use num_traits::Zero;
+use std::collections::{HashMap, VecDeque};
+
+/// Dense ids for names, numbered from 0 in the order they're first seen
+#[derive(Debug, Clone, Default)]
+pub struct Names {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+}
+
+impl Names {
+    pub fn new() -> Names {
+        Names::default()
+    }
+
+    /// Id for given name, allocating the next one if it hasn't been seen before
+    pub fn id(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    /// Id for given name, if it has been seen
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    /// Name with given id. Panics if there is no such id.
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Whether a path may step to a node, as decided by the policy given to Graph::paths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visit {
+    Allow,
+    Deny,
+    /// Allowed, but uses up one of the path's revisits
+    Revisit,
+}
+
+/// Directed graph with weighted edges, on nodes numbered from 0. Pairs well with Names.
+#[derive(Debug, Clone, Default)]
+pub struct Graph<W = u32> {
+    /// Outgoing edges for each node, as (to, weight)
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<W: Copy> Graph<W> {
+    /// Graph with given number of nodes and no edges
+    pub fn new(len: usize) -> Graph<W> {
+        Graph { edges: (0..len).map(|_| Vec::new()).collect() }
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Add an edge, adding nodes if need be to include both ends
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        if self.edges.len() <= from.max(to) {
+            self.edges.resize_with(from.max(to) + 1, Vec::new);
+        }
+        self.edges[from].push((to, weight));
+    }
+
+    /// Add edges both ways between a and b
+    pub fn add_undirected(&mut self, a: usize, b: usize, weight: W) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// Outgoing edges from given node, as (to, weight)
+    pub fn neighbours(&self, node: usize) -> &[(usize, W)] {
+        &self.edges[node]
+    }
+
+    /// Every path from start to end, as the nodes along it, found by depth-first search.
+    ///
+    /// A path may only step to a node if `policy(node, seen)` allows it, where `seen` is whether
+    /// the path has already been there. Each path may make up to `revisits` steps that the
+    /// policy answers with Visit::Revisit. Paths stop on reaching end.
+    pub fn paths(
+        &self,
+        start: usize,
+        end: usize,
+        revisits: usize,
+        mut policy: impl FnMut(usize, bool) -> Visit,
+    ) -> Vec<Vec<usize>> {
+        let mut result = Vec::new();
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        self.walk(&mut vec![start], &mut seen, end, revisits, &mut policy, &mut result);
+        result
+    }
+
+    fn walk(
+        &self,
+        path: &mut Vec<usize>,
+        seen: &mut [bool],
+        end: usize,
+        revisits: usize,
+        policy: &mut impl FnMut(usize, bool) -> Visit,
+        result: &mut Vec<Vec<usize>>,
+    ) {
+        let current = *path.last().unwrap();
+        if current == end {
+            result.push(path.clone());
+            return;
+        }
+        for &(next, _) in &self.edges[current] {
+            let revisits = match policy(next, seen[next]) {
+                Visit::Allow => revisits,
+                Visit::Revisit if revisits > 0 => revisits - 1,
+                _ => continue,
+            };
+            let first = !seen[next];
+            seen[next] = true;
+            path.push(next);
+            self.walk(path, seen, end, revisits, policy, result);
+            path.pop();
+            seen[next] = !first;
+        }
+    }
+
+    /// Number of paths that Graph::paths would return, without building them. Memoises on the
+    /// current node, the set of nodes seen and the revisits left, so the policy must depend only
+    /// on its arguments.
+    pub fn count_paths(
+        &self,
+        start: usize,
+        end: usize,
+        revisits: usize,
+        mut policy: impl FnMut(usize, bool) -> Visit,
+    ) -> u64 {
+        // Nodes seen so far, as a bitset
+        let mut seen = vec![0u64; self.len().div_ceil(64)];
+        seen[start / 64] |= 1 << (start % 64);
+        self.count_from(start, &mut seen, end, revisits, &mut policy, &mut HashMap::new())
+    }
+
+    fn count_from(
+        &self,
+        current: usize,
+        seen: &mut [u64],
+        end: usize,
+        revisits: usize,
+        policy: &mut impl FnMut(usize, bool) -> Visit,
+        cache: &mut HashMap<(usize, Vec<u64>, usize), u64>,
+    ) -> u64 {
+        if current == end {
+            return 1;
+        }
+        let key = (current, seen.to_vec(), revisits);
+        if let Some(&count) = cache.get(&key) {
+            return count;
+        }
+        let mut count = 0;
+        for &(next, _) in &self.edges[current] {
+            let (word, bit) = (next / 64, 1 << (next % 64));
+            let first = seen[word] & bit == 0;
+            let revisits = match policy(next, !first) {
+                Visit::Allow => revisits,
+                Visit::Revisit if revisits > 0 => revisits - 1,
+                _ => continue,
+            };
+            seen[word] |= bit;
+            count += self.count_from(next, seen, end, revisits, policy, cache);
+            if first {
+                seen[word] &= !bit;
+            }
+        }
+        cache.insert(key, count);
+        count
+    }
+
+    /// Number of edges on the shortest path between every pair of nodes, ignoring weights, by
+    /// running a BFS from each node. `result[from][to]` is None if there is no path.
+    pub fn bfs_all_pairs(&self) -> Vec<Vec<Option<usize>>> {
+        (0..self.len())
+            .map(|start| {
+                let mut steps = vec![None; self.len()];
+                steps[start] = Some(0);
+                let mut open = VecDeque::from([start]);
+                while let Some(current) = open.pop_front() {
+                    let next_steps = steps[current].map(|x| x + 1);
+                    for &(next, _) in &self.edges[current] {
+                        if steps[next].is_none() {
+                            steps[next] = next_steps;
+                            open.push_back(next);
+                        }
+                    }
+                }
+                steps
+            })
+            .collect()
+    }
+}
+
+impl<W: Zero + Ord + Copy> Graph<W> {
+    /// Cheapest total weight between every pair of nodes, using Floyd–Warshall.
+    /// `result[from][to]` is None if there is no path. Weights must not be negative.
+    pub fn floyd_warshall(&self) -> Vec<Vec<Option<W>>> {
+        let n = self.len();
+        let mut dist = vec![vec![None; n]; n];
+        for (from, edges) in self.edges.iter().enumerate() {
+            dist[from][from] = Some(W::zero());
+            for &(to, weight) in edges {
+                if dist[from][to].is_none_or(|x| weight < x) {
+                    dist[from][to] = Some(weight);
+                }
+            }
+        }
+        for k in 0..n {
+            let via = dist[k].clone();
+            for row in dist.iter_mut() {
+                let Some(ik) = row[k] else { continue };
+                for (ij, kj) in row.iter_mut().zip(&via) {
+                    if let Some(kj) = kj {
+                        if ij.is_none_or(|x| ik + *kj < x) {
+                            *ij = Some(ik + *kj);
+                        }
+                    }
+                }
+            }
+        }
+        dist
+    }
+
+    /// Graph of just the given key nodes, with an edge between each pair of keys weighted by the
+    /// cheapest path between them in this graph. Node i of the result is keys\[i].
+    pub fn contract(&self, keys: &[usize]) -> Graph<W> {
+        let dist = self.floyd_warshall();
+        let mut result = Graph::new(keys.len());
+        for (i, &from) in keys.iter().enumerate() {
+            for (j, &to) in keys.iter().enumerate() {
+                if let (true, Some(weight)) = (i != j, dist[from][to]) {
+                    result.add_edge(i, j, weight);
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        let mut names = Names::new();
+        assert_eq!((names.id("start"), names.id("end"), names.id("start")), (0, 1, 0));
+        assert_eq!(names.get("end"), Some(1));
+        assert_eq!(names.get("A"), None);
+        assert_eq!(names.name(1), "end");
+        assert_eq!(names.len(), 2);
+    }
+
+    /// 0 -> 1 -> 2 -> 3, with a dear shortcut 0 -> 3 and a dead end at 4
+    fn graph() -> Graph {
+        let mut graph = Graph::new(5);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, 2);
+        graph.add_edge(2, 3, 3);
+        graph.add_edge(0, 3, 10);
+        graph.add_undirected(3, 4, 1);
+        graph
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let graph = graph();
+        let dist = graph.floyd_warshall();
+        assert_eq!(dist[0], vec![Some(0), Some(1), Some(3), Some(6), Some(7)]);
+        assert_eq!(dist[4], vec![None, None, None, Some(1), Some(0)]);
+        let steps = graph.bfs_all_pairs();
+        assert_eq!(steps[0], vec![Some(0), Some(1), Some(2), Some(1), Some(2)]);
+        assert_eq!(steps[3], vec![None, None, None, Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn test_paths() {
+        // start - a - end, with b hanging off a and c hanging off start
+        let mut graph: Graph = Graph::new(5);
+        for (x, y) in [(0, 2), (2, 1), (2, 3), (0, 4)] {
+            graph.add_undirected(x, y, 1);
+        }
+        let policy = |node: usize, seen: bool| match (node, seen) {
+            (0, _) => Visit::Deny,
+            (2, _) => Visit::Allow,
+            (_, false) => Visit::Allow,
+            (_, true) => Visit::Revisit,
+        };
+        assert_eq!(graph.paths(0, 1, 0, policy), vec![vec![0, 2, 1], vec![0, 2, 3, 2, 1]]);
+        assert_eq!(graph.count_paths(0, 1, 0, policy), 2);
+        assert_eq!(graph.paths(0, 1, 1, policy).len(), 3);
+        assert_eq!(graph.count_paths(0, 1, 1, policy), 3);
+        assert_eq!(graph.count_paths(0, 4, 1, |_, _| Visit::Deny), 0);
+    }
+
+    #[test]
+    fn test_count_paths_large() {
+        // More nodes than fit in one word of the seen set: a chain ending in a diamond
+        let mut graph: Graph = Graph::new(200);
+        for i in 0..197 {
+            graph.add_edge(i, i + 1, 1);
+        }
+        graph.add_edge(197, 199, 1);
+        graph.add_edge(196, 198, 1);
+        graph.add_edge(198, 199, 1);
+        assert_eq!(
+            graph.count_paths(0, 199, 0, |_, seen| if seen { Visit::Deny } else { Visit::Allow }),
+            2
+        );
+    }
+
+    #[test]
+    fn test_contract() {
+        let contracted = graph().contract(&[0, 3, 4]);
+        assert_eq!(contracted.len(), 3);
+        assert_eq!(contracted.neighbours(0), &[(1, 6), (2, 7)]);
+        assert_eq!(contracted.neighbours(1), &[(2, 1)]);
+        assert_eq!(contracted.neighbours(2), &[(1, 1)]);
+    }
+}
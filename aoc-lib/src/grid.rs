@@ -1,4 +1,6 @@
+use crate::common::ParseError;
 use crate::data::Point2;
+use anyhow::{bail, Result};
 use std::fmt::{Display, Formatter};
 
 pub type GridPos = Point2<i32>;
@@ -29,7 +31,7 @@ impl<T> Grid<T> {
     }
 
     /// Create grid, copying values from source
-    pub fn from_2d(source: &Vec<Vec<T>>) -> Grid<T>
+    pub fn from_2d(source: &[Vec<T>]) -> Grid<T>
     where
         T: Clone,
     {
@@ -53,6 +55,48 @@ impl<T> Grid<T> {
         Grid { vals: source, dim }
     }
 
+    /// Parse grid from text, one row per line, converting each byte with converter.
+    ///
+    /// Line endings (`\n` or `\r\n`), trailing whitespace and empty lines are ignored. Rows
+    /// shorter than the longest are padded with fill if given; otherwise they're an error.
+    pub fn parse(text: &str, fill: Option<T>, converter: impl Fn(u8) -> T) -> Result<Grid<T>>
+    where
+        T: Clone,
+    {
+        // Byte offset and trimmed content of each row
+        let mut offset = 0;
+        let rows: Vec<(usize, &str)> = text
+            .split_inclusive('\n')
+            .map(|line| {
+                offset += line.len();
+                (offset - line.len(), line.trim_end())
+            })
+            .filter(|(_, row)| !row.is_empty())
+            .collect();
+        let w = match &fill {
+            Some(_) => rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0),
+            None => rows.first().map(|(_, row)| row.len()).unwrap_or(0),
+        };
+        if w == 0 {
+            bail!("Grid has no rows");
+        }
+        let mut vals = Vec::with_capacity(w * rows.len());
+        for (y, &(offset, row)) in rows.iter().enumerate() {
+            vals.extend(row.bytes().map(&converter));
+            match &fill {
+                Some(fill) => vals.resize(w * (y + 1), fill.clone()),
+                None if row.len() != w => {
+                    // The ParseError's location gives the line
+                    let message = format!("Row has width {}, expected {}", row.len(), w);
+                    let at = offset + row.len().min(w);
+                    return Err(ParseError::new(text, at, row.len().abs_diff(w), message).into());
+                }
+                None => (),
+            }
+        }
+        Ok(Grid::from_1d(vals, w))
+    }
+
     /// Get single value from grid
     pub fn get(&self, x: i32, y: i32) -> &T {
         &self.vals[(y * self.dim.x + x) as usize]
@@ -106,3 +150,23 @@ impl<T: GridChar> Display for Grid<T> {
 pub trait GridChar {
     fn to_grid_char(&self) -> char;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("12\r\n34 \n\n", None, |x| x - b'0').unwrap();
+        assert_eq!(grid.dim(), &GridDim::new(2, 2));
+        assert_eq!(grid.vec(), &vec![1, 2, 3, 4]);
+        let grid = Grid::parse("  .#\n.\n", Some(b' '), |x| x).unwrap();
+        assert_eq!(grid.dim(), &GridDim::new(4, 2));
+        assert_eq!(grid.vec(), &b"  .#.   ".to_vec());
+        let error = Grid::parse("123\n45\n678", None, |x| x).unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!(error.message, "Row has width 2, expected 3");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert!(Grid::parse("\n \n", Some(0), |x| x).is_err());
+    }
+}
@@ -1,9 +1,20 @@
+use crate::bench::{measure, BenchOptions, BenchReport};
+use crate::cancel;
 use crate::common::*;
 use crate::data::Grid;
-use anyhow::{Context, Result};
-use std::fmt::Display;
-use std::path::{Path, PathBuf};
-use std::time::SystemTime;
+use crate::input;
+use crate::report::{PartOutcome, PartReport, RunReport, TextRenderer};
+use anyhow::{anyhow, bail, Context, Result};
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Read;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::panic::{AssertUnwindSafe, PanicHookInfo};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 /// Solution for a day's puzzle
 pub trait Solution<I, O> {
@@ -13,39 +24,385 @@ pub trait Solution<I, O> {
     fn parse_input(&self, resource: &dyn Resource) -> Result<I>;
     /// Solution to puzzle part 1
     fn solve_part1(&self, input: &I) -> Result<O>;
-    /// Solution to puzzle part 2
-    fn solve_part2(&self, input: &I) -> Result<O>;
+    /// Solution to puzzle part 2. Not applicable unless this or solve_part2_with is overridden.
+    fn solve_part2(&self, _input: &I) -> Result<O> {
+        Err(Unsolved::NotApplicable.into())
+    }
+    /// Solution to puzzle part 2, given part 1's answer if it has one. Override this instead of
+    /// solve_part2 to reuse part 1's work; part 1 must be solved anyway when it's None.
+    fn solve_part2_with(&self, input: &I, _part1: Option<&O>) -> Result<O> {
+        self.solve_part2(input)
+    }
+    /// Parts this puzzle has, in the order they should be run
+    fn parts(&self) -> &'static [u8] {
+        &[1, 2]
+    }
+    /// Solution to given puzzle part, given part 1's answer if it's been solved. Override this for
+    /// puzzles with more than two parts.
+    fn solve_part(&self, input: &I, part: u8, part1: Option<&O>) -> Result<O> {
+        match part {
+            1 => self.solve_part1(input),
+            2 => self.solve_part2_with(input, part1),
+            _ => Err(Unsolved::NotApplicable.into()),
+        }
+    }
+}
+
+impl<S: Solution<I, O>, I, O> Solution<I, O> for &S {
+    fn info(&self) -> SolutionInfo {
+        (*self).info()
+    }
+
+    fn parse_input(&self, resource: &dyn Resource) -> Result<I> {
+        (*self).parse_input(resource)
+    }
+
+    fn solve_part1(&self, input: &I) -> Result<O> {
+        (*self).solve_part1(input)
+    }
+
+    fn solve_part2(&self, input: &I) -> Result<O> {
+        (*self).solve_part2(input)
+    }
+
+    fn solve_part2_with(&self, input: &I, part1: Option<&O>) -> Result<O> {
+        (*self).solve_part2_with(input, part1)
+    }
+
+    fn parts(&self) -> &'static [u8] {
+        (*self).parts()
+    }
+
+    fn solve_part(&self, input: &I, part: u8, part1: Option<&O>) -> Result<O> {
+        (*self).solve_part(input, part, part1)
+    }
+}
+
+/// Error returned for a part that has no answer, which is reported as such rather than failing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unsolved {
+    /// Puzzle doesn't have this part, e.g. day 25 part 2
+    NotApplicable,
+    /// Part hasn't been solved in code (yet)
+    NotImplemented,
+}
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unsolved::NotApplicable => write!(f, "not applicable"),
+            Unsolved::NotImplemented => write!(f, "not solved yet"),
+        }
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+/// Answer to a puzzle part, as reported by the harness.
+///
+/// Solutions can return anything that converts into this, so each day keeps whatever output type
+/// suits it; days whose parts have different kinds of answer can return it directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Multi-line ASCII art, e.g. a rendered screen
+    Art(String),
+}
+
+impl Answer {
+    /// Check against an expected answer in text form. Integers are compared numerically.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Int(x) => expected.trim().parse::<i128>().is_ok_and(|e| e == *x),
+            Answer::Text(x) | Answer::Art(x) => x.trim() == expected.trim(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::Text(x) | Answer::Art(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(x: $t) -> Answer {
+                Answer::Int(x as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(x: String) -> Answer {
+        Answer::Text(x)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(x: &str) -> Answer {
+        Answer::Text(x.to_string())
+    }
 }
 
 /// Run the solution for a day and output part 1 and 2 results
-pub fn run_solution<S: Solution<I, O>, I, O: Display>(solution: &S) -> Result<()> {
+pub fn run_solution<S: Solution<I, O>, I, O: Clone + Into<Answer>>(solution: &S) -> Result<()> {
     // Get info from solution
     let info = solution.info();
-    println!("\n--- [{}] Day {}: {} ---", info.year, info.day, info.title);
     // Create resource using year/day from info
     let resource = FileResource::new("", info.year, info.day);
-    // Call proc on solution to parse input into relevant part1/2 input type
-    let time = SystemTime::now();
-    println!("\nParse input");
-    let input = solution.parse_input(&resource)?;
-    println!("[{:?}]", time.elapsed()?);
-    // Solve part 1
-    let time = SystemTime::now();
-    println!("\nPart 1:");
-    println!("{}", solution.solve_part1(&input)?);
-    println!("[{:?}]", time.elapsed()?);
-    // Solve part 2
-    let time = SystemTime::now();
-    println!("\nPart 2:");
-    println!("{}", solution.solve_part2(&input)?);
-    println!("[{:?}]", time.elapsed()?);
+    // Output each step as soon as it's done, so a slow part 2 doesn't hold up part 1's answer
+    let report = SolutionAdapter::new(solution).run_with_progress(
+        &resource,
+        &RunLimits::default(),
+        &mut |report| print!("{}", TextRenderer.render_progress(report)),
+    );
+    report.to_result()
+}
 
-    Ok(())
+/// Object-safe view of a solution, with answers converted to Answer.
+///
+/// Solution is generic over its input and output types, so different days cannot be stored
+/// together; this can, which lets runners and the like iterate over every day.
+pub trait DynSolution {
+    /// Solution metadata
+    fn info(&self) -> SolutionInfo;
+    /// Parse input from given resource and solve each part, timing each step. Errors are
+    /// recorded in the report.
+    fn run(&self, resource: &dyn Resource, limits: &RunLimits) -> RunReport {
+        self.run_with_progress(resource, limits, &mut |_| ())
+    }
+    /// As run, calling progress with the report so far once input is parsed and again as each
+    /// part finishes
+    fn run_with_progress(
+        &self,
+        resource: &dyn Resource,
+        limits: &RunLimits,
+        progress: &mut dyn FnMut(&RunReport),
+    ) -> RunReport;
+    /// Repeatedly parse input and solve each part, collecting timing stats for each step. Unsolved
+    /// parts are left out.
+    fn bench(&self, resource: &dyn Resource, options: &BenchOptions) -> Result<BenchReport>;
+}
+
+/// Limits applied when running a solution
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunLimits {
+    /// Time limit for each part. Running parts are cancelled (see `cancel`) once it's exceeded,
+    /// and reported as timed out.
+    pub part_timeout: Option<Duration>,
+}
+
+/// Adapts a Solution into a DynSolution, pinning down its input and output types
+pub struct SolutionAdapter<S, I, O> {
+    solution: S,
+    types: PhantomData<fn(I) -> O>,
+}
+
+impl<S: Solution<I, O>, I, O: Clone + Into<Answer>> SolutionAdapter<S, I, O> {
+    pub fn new(solution: S) -> Self {
+        Self { solution, types: PhantomData }
+    }
+}
+
+impl<S: Solution<I, O>, I, O: Clone + Into<Answer>> DynSolution for SolutionAdapter<S, I, O> {
+    fn info(&self) -> SolutionInfo {
+        self.solution.info()
+    }
+
+    fn run_with_progress(
+        &self,
+        resource: &dyn Resource,
+        limits: &RunLimits,
+        progress: &mut dyn FnMut(&RunReport),
+    ) -> RunReport {
+        // Call proc on solution to parse input into relevant part1/2 input type
+        let time = Instant::now();
+        let input = catch_panic(|| parse_from(&self.solution, resource));
+        let mut report =
+            RunReport { info: self.info(), parse: time.elapsed(), error: None, parts: Vec::new() };
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                report.error = Some(format!("{:#}", e));
+                progress(&report);
+                return report;
+            }
+        };
+        progress(&report);
+        // Part 1's answer is handed on to later parts, so they can reuse its work
+        let mut part1 = None;
+        for &part in self.solution.parts() {
+            let time = Instant::now();
+            let solve = || catch_panic(|| self.solution.solve_part(&input, part, part1.as_ref()));
+            let result = match limits.part_timeout {
+                Some(limit) => cancel::with_time_limit(limit, solve),
+                None => solve(),
+            };
+            let duration = time.elapsed();
+            // Solutions that don't check for cancellation will run to completion, but still
+            // count as timed out
+            let outcome = match (result, limits.part_timeout) {
+                (_, Some(limit)) if duration > limit => PartOutcome::Timeout(limit),
+                (Ok(answer), _) => {
+                    if part == 1 {
+                        part1 = Some(answer.clone());
+                    }
+                    PartOutcome::Answer(answer.into())
+                }
+                (Err(e), _) => match e.downcast_ref::<Unsolved>() {
+                    Some(unsolved) => PartOutcome::Unsolved(*unsolved),
+                    None => PartOutcome::Error(format!("{:#}", e)),
+                },
+            };
+            report.parts.push(PartReport { part, outcome, duration });
+            progress(&report);
+        }
+        report
+    }
+
+    fn bench(&self, resource: &dyn Resource, options: &BenchOptions) -> Result<BenchReport> {
+        let (input, parse) =
+            catch_panic(|| measure(options, || parse_from(&self.solution, resource)))?;
+        let mut parts = Vec::new();
+        let mut part1 = None;
+        for &part in self.solution.parts() {
+            let solve = || self.solution.solve_part(&input, part, part1.as_ref());
+            match catch_panic(|| measure(options, solve)) {
+                Ok((answer, stats)) => {
+                    if part == 1 {
+                        part1 = Some(answer);
+                    }
+                    parts.push((part, stats));
+                }
+                Err(e) if e.is::<Unsolved>() => (),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(BenchReport { info: self.info(), warmup: options.warmup, parse, parts })
+    }
+}
+
+/// Parse input from given resource, naming the resource in any ParseError
+fn parse_from<S: Solution<I, O>, I, O>(solution: &S, resource: &dyn Resource) -> Result<I> {
+    solution.parse_input(resource).map_err(|e| in_resource(e, || resource.name()))
+}
+
+/// Call f, converting any panic into an error, so that one bad day can't take down a whole run
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let (result, location) = quiet_panics(|| std::panic::catch_unwind(AssertUnwindSafe(f)));
+    result.unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|x| x.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_string());
+        match location {
+            Some(location) => Err(anyhow!("Panicked at {}: {}", location, message)),
+            None => Err(anyhow!("Panicked: {}", message)),
+        }
+    })
 }
 
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// Number of quiet_panics calls in progress across all threads, and the hook they replaced
+static QUIET_PANICS: Mutex<(usize, Option<Arc<PanicHook>>)> = Mutex::new((0, None));
+
+thread_local! {
+    /// Depth of quiet_panics calls on this thread
+    static QUIET_DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// Where the last quietened panic on this thread happened
+    static PANIC_LOCATION: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+/// Call f with the panic hook silenced for this thread, so that panics which are about to be
+/// caught aren't also dumped to stderr. Returns the location of the last panic, if any. Other
+/// threads still get the previous hook, which is put back once no calls are in progress.
+fn quiet_panics<T>(f: impl FnOnce() -> T) -> (T, Option<String>) {
+    {
+        let mut state = QUIET_PANICS.lock().unwrap_or_else(|e| e.into_inner());
+        if state.0 == 0 {
+            let previous = Arc::new(std::panic::take_hook());
+            let fallback = previous.clone();
+            std::panic::set_hook(Box::new(move |info| {
+                if QUIET_DEPTH.get() == 0 {
+                    fallback(info);
+                } else {
+                    PANIC_LOCATION.set(info.location().map(|x| x.to_string()));
+                }
+            }));
+            state.1 = Some(previous);
+        }
+        state.0 += 1;
+    }
+    QUIET_DEPTH.set(QUIET_DEPTH.get() + 1);
+    let result = f();
+    QUIET_DEPTH.set(QUIET_DEPTH.get() - 1);
+    let location = PANIC_LOCATION.take();
+    let mut state = QUIET_PANICS.lock().unwrap_or_else(|e| e.into_inner());
+    state.0 -= 1;
+    if state.0 == 0 {
+        // Drop the quiet hook first, so it lets go of the previous one
+        drop(std::panic::take_hook());
+        if let Some(previous) = state.1.take() {
+            match Arc::try_unwrap(previous) {
+                Ok(previous) => std::panic::set_hook(previous),
+                Err(previous) => std::panic::set_hook(Box::new(move |info| previous(info))),
+            }
+        }
+    }
+    (result, location)
+}
+
+/// Boxed DynSolution, as held in a registry. Safe to share between threads.
+pub type BoxedSolution = Box<dyn DynSolution + Send + Sync>;
+
+/// Box a solution for inclusion in a registry
+pub fn register<S, I, O>(solution: S) -> BoxedSolution
+where
+    S: Solution<I, O> + Send + Sync + 'static,
+    I: 'static,
+    O: Clone + Into<Answer> + 'static,
+{
+    Box::new(SolutionAdapter::new(solution))
+}
+
+/// Which part of a puzzle to solve
 pub enum SolutionPart {
     One,
     Two,
+    /// Any further part, for puzzles with more than two
+    Other(u8),
+}
+
+impl SolutionPart {
+    /// Part number, starting from 1
+    pub fn number(&self) -> u8 {
+        match self {
+            SolutionPart::One => 1,
+            SolutionPart::Two => 2,
+            SolutionPart::Other(part) => *part,
+        }
+    }
+}
+
+impl From<u8> for SolutionPart {
+    fn from(part: u8) -> Self {
+        match part {
+            1 => SolutionPart::One,
+            2 => SolutionPart::Two,
+            _ => SolutionPart::Other(part),
+        }
+    }
 }
 
 /// Test-run solution on default test input
@@ -70,7 +427,7 @@ pub fn test_inline<S: Solution<I, O>, I, O>(
     part: SolutionPart,
     text: &'static str,
 ) -> O {
-    test_solution_inner(solution, part, &InlineResource { text })
+    test_solution_inner(solution, part, &InlineResource::new(text))
 }
 
 fn test_solution_inner<S: Solution<I, O>, I, O>(
@@ -78,12 +435,43 @@ fn test_solution_inner<S: Solution<I, O>, I, O>(
     part: SolutionPart,
     resource: &dyn Resource,
 ) -> O {
-    // We're "just" testing, so panics are probably okay here
-    let input = solution.parse_input(resource).unwrap();
-    match part {
-        SolutionPart::One => solution.solve_part1(&input).unwrap(),
-        SolutionPart::Two => solution.solve_part2(&input).unwrap(),
+    // We're "just" testing, so panics are probably okay here. {:?} includes the whole error chain.
+    try_solve(solution, part, resource).unwrap_or_else(|e| panic!("{:?}", e))
+}
+
+/// Parse input from given resource and solve a part. Errors say which resource and part failed.
+pub fn try_solve<S: Solution<I, O>, I, O>(
+    solution: &S,
+    part: SolutionPart,
+    resource: &dyn Resource,
+) -> Result<O> {
+    let input = parse_from(solution, resource)
+        .with_context(|| format!("Failed to parse input from {}", resource.name()))?;
+    solution
+        .solve_part(&input, part.number(), None)
+        .with_context(|| format!("Part {} failed on {}", part.number(), resource.name()))
+}
+
+/// Check that a part gives the expected answer on given resource, without panicking. On a wrong
+/// answer, the error gives the resource and part, and expected vs actual.
+pub fn check_solution<S: Solution<I, O>, I, O: PartialEq + Debug>(
+    solution: &S,
+    part: SolutionPart,
+    resource: &dyn Resource,
+    expected: O,
+) -> Result<()> {
+    let number = part.number();
+    let actual = try_solve(solution, part, resource)?;
+    if actual != expected {
+        bail!(
+            "Wrong answer for part {} on {}\n  expected: {:?}\n    actual: {:?}",
+            number,
+            resource.name(),
+            expected,
+            actual
+        );
     }
+    Ok(())
 }
 
 /// Resource to pull solution input from
@@ -94,50 +482,67 @@ pub trait Resource {
     /// Read u8 vec from resource
     fn as_u8(&self) -> Result<Vec<u8>>;
 
+    /// Description of the resource for messages, e.g. its path
+    fn name(&self) -> String {
+        "unnamed resource".to_string()
+    }
+
+    /// Text of the resource, borrowed if the resource already holds it in memory. Iterate over its
+    /// lines, paragraphs or records without copying each one.
+    fn text(&self) -> Result<ResourceText<'_>> {
+        self.as_str().map(ResourceText::new)
+    }
+
     /// Read string lines from resource. Filters out empty lines.
     fn as_str_lines(&self) -> Result<Vec<String>> {
-        let lines = self.as_str()?;
-        Ok(lines.split('\n').filter(|x| !x.is_empty()).map(|x| x.to_owned()).collect())
+        Ok(self.text()?.lines().map(|x| x.to_owned()).collect())
     }
 
-    /// Read grid of u8 from resource
+    /// Read grid of u8 from resource, one row per line. Rows must all be the same width.
     fn as_u8_grid(&self, converter: fn(u8) -> u8) -> Result<Grid<u8>> {
-        let input = self.as_u8()?;
-        let w = input.iter().enumerate().find(|(_, &x)| x < 32).unwrap_or((input.len(), &0)).0;
-        let grid_raw = input.iter().filter(|&&x| x >= 32).map(|&x| converter(x)).collect();
-        Ok(Grid::from_1d(grid_raw, w))
+        Grid::parse(&self.text()?, None, converter)
+    }
+
+    /// Read grid of u8 from resource, padding short rows with fill (before conversion)
+    fn as_u8_grid_padded(&self, converter: fn(u8) -> u8, fill: u8) -> Result<Grid<u8>> {
+        Grid::parse(&self.text()?, Some(converter(fill)), converter)
     }
 }
 
-const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+/// Text read from a resource, which the iterators below borrow from
+pub struct ResourceText<'a>(Cow<'a, str>);
 
-pub fn resource_path(suffix: &str, year: u32, day: u8) -> Result<PathBuf> {
-    // Look in ../aoc-yyyy/resource/dayxx.suffix
-    let mut path = Path::new(MANIFEST_DIR)
-        .join("..")
-        .join(format!("aoc-{}", year))
-        .join("resource")
-        .join(format!("day{:02}.{}", day, suffix));
-    if !path.exists() {
-        // Look in ../aoc-secret/yyyy/dayxx.suffix
-        path = Path::new(MANIFEST_DIR)
-            .join("..")
-            .join("aoc-secret")
-            .join(format!("{}", year))
-            .join(format!("day{:02}.{}", day, suffix));
+impl<'a> ResourceText<'a> {
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self(text.into())
+    }
+
+    /// Lines without their line endings, skipping empty lines
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        lines(&self.0)
+    }
+
+    /// Blocks of lines separated by blank lines
+    pub fn paragraphs(&self) -> impl Iterator<Item = &str> {
+        paragraphs(&self.0)
+    }
+
+    /// Trimmed, non-empty records split on given delimiter
+    pub fn records<'b>(&'b self, delim: &'b str) -> impl Iterator<Item = &'b str> {
+        records(&self.0, delim)
     }
-    path.canonicalize().with_context(|| format!("Failed to canonicalize path: {}", path.display()))
 }
 
-fn file_res_as_str(filename: &str, year: u32, day: u8) -> Result<String> {
-    let path = resource_path(filename, year, day)?;
-    std::fs::read_to_string(&path)
-        .with_context(|| format!("Failed to read from {}", path.display()))
+impl Deref for ResourceText<'_> {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
 }
 
-fn file_res_as_u8(filename: &str, year: u32, day: u8) -> Result<Vec<u8>> {
-    let path = resource_path(filename, year, day)?;
-    std::fs::read(&path).with_context(|| format!("Failed to read from {}", path.display()))
+/// Path of a day's resource file, as found by `input::locate`
+pub fn resource_path(suffix: &str, year: u32, day: u8) -> Result<PathBuf> {
+    input::locate(suffix, year, day)
 }
 
 /// Resource corresponding to a file on disk
@@ -145,35 +550,131 @@ fn file_res_as_u8(filename: &str, year: u32, day: u8) -> Result<Vec<u8>> {
 /// Puzzle inputs are expected to be named according to the day. Suffix can be used to differentiate
 /// between real and test inputs, multiple test inputs, etc
 pub struct FileResource {
-    suffix: &'static str,
+    suffix: Cow<'static, str>,
     year: u32,
     day: u8,
+    /// Where the file was found, once looked for (errors as text, as they can't be cloned)
+    path: OnceLock<Result<PathBuf, String>>,
 }
 
 impl FileResource {
-    pub fn new(suffix: &'static str, year: u32, day: u8) -> Self {
-        Self { suffix, year, day }
+    pub fn new(suffix: impl Into<Cow<'static, str>>, year: u32, day: u8) -> Self {
+        Self { suffix: suffix.into(), year, day, path: OnceLock::new() }
+    }
+
+    /// Path of the file, looked for on first use
+    fn path(&self) -> Result<&PathBuf> {
+        let path = self.path.get_or_init(|| {
+            resource_path(&self.suffix, self.year, self.day).map_err(|e| format!("{:#}", e))
+        });
+        path.as_ref().map_err(|e| anyhow!("{}", e))
     }
 }
 
 impl Resource for FileResource {
     fn as_str(&self) -> Result<String> {
-        file_res_as_str(self.suffix, self.year, self.day)
+        let path = self.path()?;
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read from {}", path.display()))
+    }
+
+    fn as_u8(&self) -> Result<Vec<u8>> {
+        let path = self.path()?;
+        std::fs::read(path).with_context(|| format!("Failed to read from {}", path.display()))
+    }
+
+    fn name(&self) -> String {
+        match self.path() {
+            Ok(path) => path.display().to_string(),
+            Err(_) => format!("[{}] day{:02}.{}", self.year, self.day, self.suffix),
+        }
+    }
+}
+
+/// Resource corresponding to an arbitrary file, e.g. one given on the command line
+pub struct PathResource {
+    path: PathBuf,
+}
+
+impl PathResource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Resource for PathResource {
+    fn as_str(&self) -> Result<String> {
+        std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read from {}", self.path.display()))
+    }
+
+    fn as_u8(&self) -> Result<Vec<u8>> {
+        std::fs::read(&self.path)
+            .with_context(|| format!("Failed to read from {}", self.path.display()))
+    }
+
+    fn name(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+/// Resource corresponding to standard input. Read in full on first use, and kept for later uses.
+#[derive(Default)]
+pub struct StdinResource {
+    text: OnceLock<String>,
+}
+
+impl StdinResource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read(&self) -> Result<&str> {
+        if let Some(text) = self.text.get() {
+            return Ok(text);
+        }
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text).context("Failed to read from stdin")?;
+        Ok(self.text.get_or_init(|| text))
+    }
+}
+
+impl Resource for StdinResource {
+    fn as_str(&self) -> Result<String> {
+        self.read().map(|x| x.to_string())
     }
 
     fn as_u8(&self) -> Result<Vec<u8>> {
-        file_res_as_u8(self.suffix, self.year, self.day)
+        self.read().map(str_to_u8)
+    }
+
+    fn text(&self) -> Result<ResourceText<'_>> {
+        self.read().map(ResourceText::new)
+    }
+
+    fn name(&self) -> String {
+        "stdin".to_string()
+    }
+}
+
+/// Name for inline text: its first line, shortened if need be
+pub(crate) fn inline_name(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default();
+    match line.char_indices().nth(40) {
+        Some((i, _)) => format!("inline text \"{}...\"", &line[..i]),
+        None if line.len() < text.trim_end().len() => format!("inline text \"{}...\"", line),
+        None => format!("inline text \"{}\"", line),
     }
 }
 
-/// Resource corresponding to inline text
+/// Resource corresponding to inline text, either static or built at runtime
 pub struct InlineResource {
-    text: &'static str,
+    text: Cow<'static, str>,
 }
 
 impl InlineResource {
-    pub fn new(text: &'static str) -> Self {
-        Self { text }
+    pub fn new(text: impl Into<Cow<'static, str>>) -> Self {
+        Self { text: text.into() }
     }
 }
 
@@ -183,11 +684,20 @@ impl Resource for InlineResource {
     }
 
     fn as_u8(&self) -> Result<Vec<u8>> {
-        Ok(str_to_u8(self.text))
+        Ok(str_to_u8(&self.text))
+    }
+
+    fn text(&self) -> Result<ResourceText<'_>> {
+        Ok(ResourceText::new(self.text.as_ref()))
+    }
+
+    fn name(&self) -> String {
+        inline_name(&self.text)
     }
 }
 
 /// Simple solution metadata: title, date
+#[derive(Debug, Clone, Copy)]
 pub struct SolutionInfo {
     title: &'static str,
     year: u32,
@@ -198,4 +708,98 @@ impl SolutionInfo {
     pub fn new(title: &'static str, year: u32, day: u8) -> SolutionInfo {
         SolutionInfo { title, year, day }
     }
+
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums numbers, one per line
+    struct Sum;
+
+    impl Solution<Vec<i64>, i64> for Sum {
+        fn info(&self) -> SolutionInfo {
+            SolutionInfo::new("Sum", 2022, 1)
+        }
+
+        fn parse_input(&self, resource: &dyn Resource) -> Result<Vec<i64>> {
+            parse_lines(&resource.text()?, parse_str)
+        }
+
+        fn solve_part1(&self, input: &Vec<i64>) -> Result<i64> {
+            Ok(input.iter().sum())
+        }
+    }
+
+    #[test]
+    fn test_check_solution() {
+        let resource = InlineResource::new("1\n2\n3");
+        assert!(check_solution(&Sum, SolutionPart::One, &resource, 6).is_ok());
+        let error = check_solution(&Sum, SolutionPart::One, &resource, 7).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Wrong answer for part 1 on inline text \"1...\"\n  expected: 7\n    actual: 6"
+        );
+    }
+
+    #[test]
+    fn test_owned_resources() -> Result<()> {
+        let text: String = (1..=4).map(|x| format!("{}\n", x)).collect();
+        check_solution(&Sum, SolutionPart::One, &InlineResource::new(text), 10)?;
+        let suffix = format!("test.{}", 1);
+        let resource = FileResource::new(suffix, 2022, 9);
+        assert!(resource.name().ends_with("day09.test.1"));
+        let resource = PathResource::new(resource_path("test.1", 2022, 9)?);
+        assert_eq!(resource.as_str()?, FileResource::new("test.1", 2022, 9).as_str()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resource_text() -> Result<()> {
+        let resource = InlineResource::new("1,2\n3\n\n4\n");
+        let text = resource.text()?;
+        assert!(matches!(text.0, Cow::Borrowed(_)));
+        assert_eq!(text.lines().collect::<Vec<_>>(), vec!["1,2", "3", "4"]);
+        assert_eq!(text.paragraphs().collect::<Vec<_>>(), vec!["1,2\n3", "4"]);
+        assert_eq!(text.records(",").collect::<Vec<_>>(), vec!["1", "2\n3\n\n4"]);
+        let resource = FileResource::new("test", 2022, 1);
+        assert_eq!(resource.text()?.paragraphs().count(), 5);
+        let resource = InlineResource::new("12\r\n3\r\n");
+        assert!(resource.as_u8_grid(|x| x - b'0').is_err());
+        assert_eq!(resource.as_u8_grid_padded(|x| x - b'0', b'0')?.vec(), &vec![1, 2, 3, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let error = catch_panic(|| -> Result<()> { panic!("boom") }).unwrap_err().to_string();
+        assert!(error.starts_with("Panicked at aoc-lib/src/harness.rs:"), "{}", error);
+        assert!(error.ends_with(": boom"));
+        assert_eq!(catch_panic(|| Ok(1)).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_try_solve_errors() {
+        let error = try_solve(&Sum, SolutionPart::One, &InlineResource::new("1\nx")).unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            "Failed to parse input from inline text \"1...\": Could not parse 'x'\n \
+            --> inline text \"1...\":2:1\n  |\n2 | x\n  | ^"
+        );
+        let resource = InlineResource::new("1");
+        let error = try_solve(&Sum, SolutionPart::Two, &resource).unwrap_err();
+        assert_eq!(format!("{:#}", error), "Part 2 failed on inline text \"1\": not applicable");
+    }
 }
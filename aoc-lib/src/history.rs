@@ -0,0 +1,298 @@
+use crate::bench::{BenchReport, Stats};
+use crate::common::parse_str;
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default location for benchmark history, relative to the working directory
+pub const DEFAULT_RESULTS_DIR: &str = "bench-results";
+const HISTORY_FILE: &str = "history.csv";
+const HEADER: &str = "timestamp,commit,year,day,phase,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// Benchmark stats for a single phase (parse or part) of a day, from a single run
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRecord {
+    /// Seconds since the unix epoch
+    pub timestamp: u64,
+    pub commit: String,
+    pub year: u32,
+    pub day: u8,
+    pub phase: String,
+    pub stats: Stats,
+}
+
+/// Key identifying what a record measures
+type RecordKey = (u32, u8, String);
+
+impl BenchRecord {
+    /// Create records for each phase of a benchmark report
+    pub fn from_report(report: &BenchReport, commit: &str, timestamp: u64) -> Vec<BenchRecord> {
+        let parts = report.parts.iter().map(|(part, stats)| (format!("part{}", part), stats));
+        std::iter::once(("parse".to_string(), &report.parse))
+            .chain(parts)
+            .map(|(phase, stats)| BenchRecord {
+                timestamp,
+                commit: commit.to_string(),
+                year: report.info.year(),
+                day: report.info.day(),
+                phase,
+                stats: *stats,
+            })
+            .collect()
+    }
+
+    fn key(&self) -> RecordKey {
+        (self.year, self.day, self.phase.clone())
+    }
+
+    fn to_csv(&self) -> String {
+        let s = &self.stats;
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit,
+            self.year,
+            self.day,
+            self.phase,
+            s.runs,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.mean.as_nanos(),
+            s.stddev.as_nanos()
+        )
+    }
+
+    fn from_csv(line: &str) -> Result<BenchRecord> {
+        let fields: Vec<&str> = line.split(',').collect();
+        if fields.len() != 10 {
+            bail!("Expected 10 fields, found {}", fields.len());
+        }
+        let nanos = |x: &str| -> Result<Duration> { Ok(Duration::from_nanos(parse_str(x)?)) };
+        Ok(BenchRecord {
+            timestamp: parse_str(fields[0])?,
+            commit: fields[1].to_string(),
+            year: parse_str(fields[2])?,
+            day: parse_str(fields[3])?,
+            phase: fields[4].to_string(),
+            stats: Stats {
+                runs: parse_str(fields[5])?,
+                min: nanos(fields[6])?,
+                median: nanos(fields[7])?,
+                mean: nanos(fields[8])?,
+                stddev: nanos(fields[9])?,
+            },
+        })
+    }
+}
+
+/// Load all records from the history in given results dir. Empty if there is no history yet.
+pub fn load_history(results_dir: &Path) -> Result<Vec<BenchRecord>> {
+    let path = results_dir.join(HISTORY_FILE);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read from {}", path.display()))?;
+    text.lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            BenchRecord::from_csv(line)
+                .with_context(|| format!("Bad record at {}:{}", path.display(), i + 1))
+        })
+        .collect()
+}
+
+/// Append records to the history in given results dir, creating it if need be
+pub fn append_history(results_dir: &Path, records: &[BenchRecord]) -> Result<()> {
+    std::fs::create_dir_all(results_dir)
+        .with_context(|| format!("Failed to create {}", results_dir.display()))?;
+    let path = results_dir.join(HISTORY_FILE);
+    let is_new = !path.exists();
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for record in records {
+        writeln!(file, "{}", record.to_csv())?;
+    }
+    Ok(())
+}
+
+/// Current git commit (with a -dirty suffix if there are local changes), or "unknown"
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|x| x.status.success())
+        .and_then(|x| String::from_utf8(x.stdout).ok())
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Current time as seconds since the unix epoch
+pub fn current_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|x| x.as_secs()).unwrap_or(0)
+}
+
+/// Median time for a phase in the baseline vs the latest run
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseComparison {
+    pub year: u32,
+    pub day: u8,
+    pub phase: String,
+    pub baseline: Duration,
+    pub latest: Duration,
+}
+
+impl PhaseComparison {
+    /// Percentage change from baseline to latest; positive means slower
+    pub fn change_percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        (self.latest.as_secs_f64() - baseline) / baseline * 100.0
+    }
+}
+
+/// Comparison of the latest benchmark run against a baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub baseline_commit: String,
+    pub latest_commit: String,
+    /// Percentage slowdown above which a phase counts as a regression
+    pub threshold_percent: f64,
+    pub phases: Vec<PhaseComparison>,
+}
+
+impl Comparison {
+    /// Phases that slowed down by more than the threshold
+    pub fn regressions(&self) -> Vec<&PhaseComparison> {
+        self.phases.iter().filter(|x| x.change_percent() > self.threshold_percent).collect()
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Baseline {} vs latest {}\n", self.baseline_commit, self.latest_commit)?;
+        for p in self.phases.iter() {
+            let flag =
+                if p.change_percent() > self.threshold_percent { "  REGRESSION" } else { "" };
+            writeln!(
+                f,
+                "[{}] Day {:02} {:<6} {:>12} -> {:>12} {:>+8.1}%{}",
+                p.year,
+                p.day,
+                p.phase,
+                format!("{:.2?}", p.baseline),
+                format!("{:.2?}", p.latest),
+                p.change_percent(),
+                flag
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare the latest run in the history against a baseline.
+///
+/// The latest run is every most-recent record from the commit of the final record. The baseline
+/// is the most recent records for the given commit (or commit prefix), or if none is given, the
+/// most recently benchmarked commit other than the latest.
+pub fn compare(
+    history: &[BenchRecord],
+    baseline_commit: Option<&str>,
+    threshold_percent: f64,
+) -> Result<Comparison> {
+    let latest_commit = match history.last() {
+        Some(x) => x.commit.clone(),
+        None => bail!("No benchmark history"),
+    };
+    let baseline_commit = match baseline_commit {
+        Some(prefix) => match history.iter().rev().find(|x| x.commit.starts_with(prefix)) {
+            Some(x) => x.commit.clone(),
+            None => bail!("No benchmark history for commit {}", prefix),
+        },
+        None => match history.iter().rev().find(|x| x.commit != latest_commit) {
+            Some(x) => x.commit.clone(),
+            None => bail!("No benchmark history for any commit other than {}", latest_commit),
+        },
+    };
+    let most_recent = |commit: &str| -> BTreeMap<RecordKey, &BenchRecord> {
+        history.iter().filter(|x| x.commit == commit).map(|x| (x.key(), x)).collect()
+    };
+    let baseline = most_recent(&baseline_commit);
+    let phases = most_recent(&latest_commit)
+        .into_iter()
+        .filter_map(|(key, latest)| {
+            baseline.get(&key).map(|base| PhaseComparison {
+                year: key.0,
+                day: key.1,
+                phase: key.2,
+                baseline: base.stats.median,
+                latest: latest.stats.median,
+            })
+        })
+        .collect();
+    Ok(Comparison { baseline_commit, latest_commit, threshold_percent, phases })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(commit: &str, day: u8, phase: &str, median_ms: u64) -> BenchRecord {
+        let d = Duration::from_millis(median_ms);
+        BenchRecord {
+            timestamp: 1671000000,
+            commit: commit.to_string(),
+            year: 2022,
+            day,
+            phase: phase.to_string(),
+            stats: Stats { runs: 10, min: d, median: d, mean: d, stddev: Duration::ZERO },
+        }
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let r = record("abc123", 16, "part2", 1500);
+        assert_eq!(BenchRecord::from_csv(&r.to_csv()).unwrap(), r);
+        assert!(BenchRecord::from_csv("1,2,3").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = vec![
+            record("aaa", 16, "part1", 100),
+            record("aaa", 16, "part2", 100),
+            record("bbb", 16, "part1", 200),
+            record("bbb", 16, "part2", 200),
+            record("ccc", 16, "part1", 105),
+            record("ccc", 16, "part2", 150),
+        ];
+        let cmp = compare(&history, None, 10.0).unwrap();
+        assert_eq!(cmp.baseline_commit, "bbb");
+        assert!(cmp.regressions().is_empty());
+
+        let cmp = compare(&history, Some("a"), 10.0).unwrap();
+        assert_eq!(cmp.baseline_commit, "aaa");
+        let regressions = cmp.regressions();
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].phase, "part2");
+
+        assert!(compare(&history, Some("zzz"), 10.0).is_err());
+        assert!(compare(&history[..2], None, 10.0).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+/// Environment variable naming a directory of puzzle inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Config file looked for in the working directory and its ancestors
+pub const CONFIG_FILE: &str = "aoc.conf";
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Input directory set by the caller (e.g. from a command line flag), which beats any other
+static INPUT_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Result of Config::load, kept after the first call (errors as text, as they can't be cloned)
+static CONFIG: OnceLock<Result<Option<Config>, String>> = OnceLock::new();
+
+/// Use given directory for puzzle inputs ahead of the environment and config file
+pub fn set_input_dir(dir: Option<PathBuf>) {
+    *INPUT_DIR.write().unwrap_or_else(|e| e.into_inner()) = dir;
+}
+
+/// Settings read from a config file.
+///
+/// The format is one `key = value` per line; blank lines and lines starting with `#` are ignored.
+/// Relative paths are taken relative to the config file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Parse config text, with paths relative to given dir
+    pub fn parse(text: &str, dir: &Path) -> Result<Config> {
+        let mut config = Config::default();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => bail!("Line {}: expected key = value", i + 1),
+            };
+            match key {
+                "input_dir" => config.input_dir = Some(dir.join(value)),
+                _ => bail!("Line {}: unrecognised key '{}'", i + 1, key),
+            }
+        }
+        Ok(config)
+    }
+
+    /// Load the nearest config file to the working directory, if there is one
+    pub fn load() -> Result<Option<Config>> {
+        let cwd = std::env::current_dir().context("Failed to get working directory")?;
+        for dir in cwd.ancestors() {
+            let path = dir.join(CONFIG_FILE);
+            if path.is_file() {
+                let text = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read from {}", path.display()))?;
+                return Config::parse(&text, dir)
+                    .with_context(|| format!("Failed to parse {}", path.display()))
+                    .map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// As load, but only looks for the config file the first time; later calls get the same
+    /// result
+    pub fn cached() -> Result<Option<&'static Config>> {
+        match CONFIG.get_or_init(|| Config::load().map_err(|e| format!("{:#}", e))) {
+            Ok(config) => Ok(config.as_ref()),
+            Err(e) => Err(anyhow!("{}", e)),
+        }
+    }
+}
+
+/// Configured input directories, most preferred first: set_input_dir, then the environment
+/// variable, then the config file
+pub fn input_dirs() -> Result<Vec<PathBuf>> {
+    let mut result = Vec::new();
+    if let Some(dir) = INPUT_DIR.read().unwrap_or_else(|e| e.into_inner()).clone() {
+        result.push(dir);
+    }
+    if let Some(dir) = std::env::var_os(INPUT_DIR_VAR).filter(|x| !x.is_empty()) {
+        result.push(PathBuf::from(dir));
+    }
+    if let Some(dir) = Config::cached()?.and_then(|x| x.input_dir.clone()) {
+        result.push(dir);
+    }
+    Ok(result)
+}
+
+/// Paths to try for a day's resource, in order.
+///
+/// Each input dir is expected to hold `<year>/dayNN.<suffix>`. Alongside those are the test inputs
+/// in `aoc-<year>/resource` and the `aoc-secret` checkout, relative to the workspace this was
+/// built from. For the real input (empty suffix) the input dirs come first; for anything else
+/// the checked-in `resource` dir does, so tests and answer checks always see the same examples.
+pub fn candidates(input_dirs: &[PathBuf], suffix: &str, year: u32, day: u8) -> Vec<PathBuf> {
+    let filename = format!("day{:02}.{}", day, suffix);
+    let workspace = Path::new(MANIFEST_DIR).join("..");
+    let resource = workspace.join(format!("aoc-{}", year)).join("resource");
+    let input_dirs = input_dirs.iter().map(|dir| dir.join(year.to_string()));
+    let dirs: Vec<PathBuf> = if suffix.is_empty() {
+        input_dirs.chain([resource]).collect()
+    } else {
+        [resource].into_iter().chain(input_dirs).collect()
+    };
+    dirs.into_iter()
+        .chain([workspace.join("aoc-secret").join(year.to_string())])
+        .map(|dir| dir.join(&filename))
+        .collect()
+}
+
+/// Find a day's resource. Fails listing every path tried if it can't be found.
+pub fn locate(suffix: &str, year: u32, day: u8) -> Result<PathBuf> {
+    let tried = candidates(&input_dirs()?, suffix, year, day);
+    match tried.iter().find(|x| x.exists()) {
+        Some(path) => path
+            .canonicalize()
+            .with_context(|| format!("Failed to canonicalize path: {}", path.display())),
+        None => bail!(
+            "Could not find day{:02}.{} for {}. Tried:\n{}\n(set {}, pass --input-dir, or add \
+            input_dir to {})",
+            day,
+            suffix,
+            year,
+            tried.iter().map(|x| format!("  {}", x.display())).collect::<Vec<_>>().join("\n"),
+            INPUT_DIR_VAR,
+            CONFIG_FILE
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config() {
+        let config =
+            Config::parse("# Inputs\n\ninput_dir = inputs\n", Path::new("/home/elf")).unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("/home/elf/inputs")));
+        let config = Config::parse("input_dir = /srv/aoc", Path::new("/home/elf")).unwrap();
+        assert_eq!(config.input_dir, Some(PathBuf::from("/srv/aoc")));
+        assert!(Config::parse("input_dir", Path::new(".")).is_err());
+        assert!(Config::parse("output_dir = x", Path::new(".")).is_err());
+    }
+
+    #[test]
+    fn test_config_cached() {
+        let (a, b) = (Config::cached().unwrap(), Config::cached().unwrap());
+        assert!(a.zip(b).is_none_or(|(a, b)| std::ptr::eq(a, b)));
+    }
+
+    #[test]
+    fn test_candidates() {
+        let dirs = [PathBuf::from("/a"), PathBuf::from("/b")];
+        let result = candidates(&dirs, "", 2022, 7);
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[0], PathBuf::from("/a/2022/day07."));
+        assert_eq!(result[1], PathBuf::from("/b/2022/day07."));
+        assert!(result[2].ends_with("aoc-2022/resource/day07."));
+        assert!(result[3].ends_with("aoc-secret/2022/day07."));
+        // Checked-in examples can't be overridden
+        let result = candidates(&dirs, "test", 2022, 7);
+        assert_eq!(result.len(), 4);
+        assert!(result[0].ends_with("aoc-2022/resource/day07.test"));
+        assert_eq!(result[1], PathBuf::from("/a/2022/day07.test"));
+        assert_eq!(result[2], PathBuf::from("/b/2022/day07.test"));
+        assert!(result[3].ends_with("aoc-secret/2022/day07.test"));
+    }
+
+    #[test]
+    fn test_locate() {
+        assert!(locate("test", 2022, 7).unwrap().ends_with("aoc-2022/resource/day07.test"));
+        let error = format!("{:#}", locate("nope", 2022, 7).unwrap_err());
+        assert!(error.contains("aoc-2022/resource/day07.nope\n"));
+        assert!(error.contains("aoc-secret/2022/day07.nope\n"));
+    }
+}
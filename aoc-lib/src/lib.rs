@@ -1,5 +1,13 @@
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod cases;
 pub mod common;
 pub mod harness;
+pub mod history;
+pub mod input;
+pub mod parallel;
+pub mod report;
 
 mod dir4;
 mod grid;
@@ -14,4 +22,5 @@ pub mod data {
     pub use crate::point3::Point3;
 }
 
+pub mod graph;
 pub mod path;
@@ -0,0 +1,61 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Number of worker threads to use by default: one per available CPU
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map(|x| x.get()).unwrap_or(1)
+}
+
+/// Apply f to every item using a pool of worker threads.
+///
+/// Results come back in the same order as the items, however long each one takes. Workers pull
+/// the next unclaimed item as they become free, so a slow item doesn't hold up the rest.
+pub fn map_parallel<T: Sync, R: Send>(
+    items: &[T],
+    threads: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= items.len() {
+                    break;
+                }
+                let result = f(&items[i]);
+                *results[i].lock().expect("no other thread panics holding this lock") =
+                    Some(result);
+            });
+        }
+    });
+    results
+        .into_iter()
+        .map(|x| x.into_inner().ok().flatten().expect("every item has been processed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_order_preserved() {
+        let items: Vec<u64> = (0..20).collect();
+        let result = map_parallel(&items, 4, |&x| {
+            // Make early items finish last
+            thread::sleep(Duration::from_millis(20 - x));
+            x * 2
+        });
+        assert_eq!(result, (0..20).map(|x| x * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(map_parallel(&[1, 2, 3], 0, |x| x + 1), vec![2, 3, 4]);
+        assert!(map_parallel(&Vec::<u8>::new(), 4, |x| *x).is_empty());
+    }
+}
@@ -1,128 +1,360 @@
-use num_traits::{Bounded, Num};
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::hash::Hash;
-
-// TODO test these - I don't think they're usable in this state, lifetimes are confused
-
-/// Find a path using breadth first search
-pub fn bfs<'a, N: Eq + Hash, ItN: Iterator<Item = &'a N>, Ctx>(
-    context: &Ctx,
-    start_node: &'a N,
-    neighbours: fn(&Ctx, &N) -> ItN,
-    is_end: fn(&Ctx, &N) -> bool,
-) -> Option<Vec<&'a N>> {
-    // Empty queue - "open". Add the start node.
-    let mut open = VecDeque::new();
-    open.push_back(start_node);
-    // Visited nodes, track where they were visited from
-    let mut prev: HashMap<&N, &N> = HashMap::new();
-    prev.insert(start_node, start_node);
-    // Dequeue from open
-    while !open.is_empty() {
-        let current = open.pop_front().expect("Queue cannot be empty");
-        for link in neighbours(context, current) {
-            // If we're connected to the end then that'll do
-            if is_end(context, link) {
-                prev.insert(link, current);
-                // Could unfold the path properly here, but we only care about length
-                let mut at = link;
-                let mut result = vec![at];
-                loop {
-                    let a = prev[at];
-                    if a == at {
-                        return Some(result);
-                    }
-                    result.push(a);
-                    at = a;
-                }
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
+
+/// Path found by a search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Nodes along the path, including start and end
+    pub nodes: Vec<N>,
+    /// Total cost of the path. For bfs, the number of steps.
+    pub cost: C,
+    /// Number of nodes expanded by the search, as a rough measure of the work done
+    pub visited: usize,
+}
+
+impl<N, C> Path<N, C> {
+    /// Node the path starts from, i.e. which of the starts was used
+    pub fn source(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    /// Node the path ends at, i.e. which end was reached
+    pub fn target(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+}
+
+/// Predicate for is_end, true for any node in the given set
+pub fn in_set<N: Eq + Hash, S: BuildHasher>(set: &HashSet<N, S>) -> impl FnMut(&N) -> bool + '_ {
+    |node| set.contains(node)
+}
+
+/// Node seen by a search, and where it was reached from. Starts are reached from themselves.
+struct Seen<N, C> {
+    node: N,
+    cost: C,
+    prev: usize,
+}
+
+/// Nodes seen so far by a search. Each is stored once, and referred to by index after that.
+struct SeenNodes<N, C> {
+    seen: Vec<Seen<N, C>>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> SeenNodes<N, C> {
+    /// Nodes seen from the outset: the starts, less any duplicates
+    fn new(starts: impl IntoIterator<Item = N>, cost: C) -> Self {
+        let mut result = SeenNodes { seen: Vec::new(), index: HashMap::new() };
+        for start in starts {
+            if let Entry::Vacant(entry) = result.index.entry(start.clone()) {
+                entry.insert(result.seen.len());
+                result.seen.push(Seen { node: start, cost, prev: result.seen.len() });
             }
-            // And connected node to open, remember prev
-            prev.entry(link).or_insert_with(|| {
-                open.push_back(link);
-                current
-            });
         }
+        result
+    }
+
+    /// Nodes from the start the given node was reached from, to the node
+    fn path_to(&self, mut i: usize) -> Vec<N> {
+        let mut result = vec![self.seen[i].node.clone()];
+        while self.seen[i].prev != i {
+            i = self.seen[i].prev;
+            result.push(self.seen[i].node.clone());
+        }
+        result.reverse();
+        result
     }
-    None
 }
 
-/// Track A* node data
-struct AStarNodeData<Node, Cost: Num> {
-    f: Cost,
-    g: Cost,
-    came_from: Node,
-}
-
-/// Find shortest path using A*
-pub fn a_star<
-    Node: Eq + Hash + Copy + Clone,
-    ItNC: IntoIterator<Item = (Node, Cost)>,
-    Ctx,
-    Cost: Num + Bounded + Copy + Ord,
->(
-    context: &Ctx,
-    start_node: &Node,
-    neighbours: fn(&Ctx, &Node) -> ItNC,
-    heuristic: fn(&Ctx, &Node) -> Cost,
-    is_end: fn(&Ctx, &Node) -> bool,
-) -> Option<Vec<Node>> {
-    let mut open = HashSet::new();
-    open.insert(*start_node);
-
-    let mut nodes: HashMap<Node, AStarNodeData<Node, Cost>> = HashMap::new();
-    nodes.insert(
-        *start_node,
-        AStarNodeData {
-            f: heuristic(context, start_node),
-            g: Cost::zero(),
-            came_from: *start_node,
-        },
-    );
-
-    while !open.is_empty() {
-        // Select "best" node from open set. This is a bit of a mess.
-        let current = open
-            .iter()
-            .map(|n| (n, nodes.get(n).map(|x| x.f).unwrap_or_else(|| Cost::max_value())))
-            .min_by(|(_, s1), (_, s2)| s1.cmp(s2))
-            .map(|(x, _)| *x)?;
-        if is_end(context, &current) {
-            // reconstruct path
-            // includes start and end nodes
-            let mut p = current;
-            let mut result = Vec::new();
-            result.push(p);
-            loop {
-                let v = nodes[&p].came_from;
-                if v == p {
-                    result.reverse();
-                    return Some(result);
-                } else {
-                    // println!("{:?}", v);
-                    result.push(v);
-                    p = v;
-                }
-            }
+/// Find the shortest path from start to any node for which is_end is true, using breadth first
+/// search. Every step costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_end: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    bfs_multi([start], neighbours, is_end)
+}
+
+/// Find the shortest path from any of the starts to any node for which is_end is true, using
+/// breadth first search. The path's source and target say which pair was connected.
+pub fn bfs_multi<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_end: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = SeenNodes::new(starts, 0);
+    let mut open: VecDeque<usize> = (0..nodes.seen.len()).collect();
+    let mut visited = 0;
+    while let Some(current) = open.pop_front() {
+        let Seen { node, cost, .. } = &nodes.seen[current];
+        let cost = *cost;
+        if is_end(node) {
+            return Some(Path { nodes: nodes.path_to(current), cost, visited });
         }
-        open.remove(&current);
-        // Check neighbours
-        let node = nodes.get(&current);
-        let current_g = node.map(|x| x.g).unwrap_or_else(|| Cost::max_value());
-        for (n, c) in neighbours(context, &current) {
-            let tentative_g = current_g + c;
-            let neighbour = nodes.get(&n);
-            if tentative_g < neighbour.map(|x| x.g).unwrap_or_else(|| Cost::max_value()) {
-                nodes.insert(
-                    n,
-                    AStarNodeData {
-                        f: tentative_g + heuristic(context, &n),
-                        g: tentative_g,
-                        came_from: current,
-                    },
-                );
-                open.insert(n);
+        visited += 1;
+        for next in neighbours(node) {
+            if let Entry::Vacant(entry) = nodes.index.entry(next.clone()) {
+                entry.insert(nodes.seen.len());
+                open.push_back(nodes.seen.len());
+                nodes.seen.push(Seen { node: next, cost: cost + 1, prev: current });
             }
         }
     }
     None
 }
+
+/// Find the cheapest path from start to any node for which is_end is true, using Dijkstra's
+/// algorithm. Costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_end: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    a_star(start, neighbours, |_| C::zero(), is_end)
+}
+
+/// Find the cheapest path from any of the starts to any node for which is_end is true, using
+/// Dijkstra's algorithm. The path's source and target say which pair was connected.
+pub fn dijkstra_multi<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_end: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (end, nodes, visited) = search(starts, neighbours, |_| C::zero(), is_end)?;
+    Some(Path { nodes: nodes.path_to(end), cost: nodes.seen[end].cost, visited })
+}
+
+/// Entry in the open set of a_star. Ordered so that BinaryHeap pops the lowest f first, and of
+/// those the one furthest from the start.
+struct Open<C> {
+    f: C,
+    g: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Open<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f.cmp(&self.f).then_with(|| self.g.cmp(&other.g))
+    }
+}
+
+impl<C: Ord> PartialOrd for Open<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Open<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Open<C> {}
+
+/// Find the cheapest path from start to any node for which is_end is true, using A*.
+///
+/// The heuristic estimates the remaining cost from a node to the end. It must never overestimate,
+/// or the path found may not be the cheapest.
+pub fn a_star<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_end: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (end, nodes, visited) = search([start], neighbours, heuristic, is_end)?;
+    Some(Path { nodes: nodes.path_to(end), cost: nodes.seen[end].cost, visited })
+}
+
+/// As a_star, but only finds the end node reached and the cost of getting there, without
+/// reconstructing the path
+pub fn a_star_cost<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_end: impl FnMut(&N) -> bool,
+) -> Option<(N, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (end, mut nodes, _) = search([start], neighbours, heuristic, is_end)?;
+    let end = nodes.seen.swap_remove(end);
+    Some((end.node, end.cost))
+}
+
+/// A* search from any of the starts, shared by the functions above. Gives the index of the end
+/// node reached, all nodes seen, and the number of nodes expanded.
+fn search<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_end: impl FnMut(&N) -> bool,
+) -> Option<(usize, SeenNodes<N, C>, usize)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = SeenNodes::new(starts, C::zero());
+    let mut open: BinaryHeap<Open<C>> = nodes
+        .seen
+        .iter()
+        .enumerate()
+        .map(|(index, seen)| Open { f: heuristic(&seen.node), g: C::zero(), index })
+        .collect();
+    let mut visited = 0;
+    while let Some(Open { g, index: current, .. }) = open.pop() {
+        let node = &nodes.seen[current].node;
+        // Nodes are pushed again when a cheaper way to them is found, rather than updated in
+        // place. Skip any entries that have been superseded.
+        if g > nodes.seen[current].cost {
+            continue;
+        }
+        if is_end(node) {
+            return Some((current, nodes, visited));
+        }
+        visited += 1;
+        for (next, step) in neighbours(node) {
+            let cost = g + step;
+            let index = match nodes.index.entry(next) {
+                Entry::Occupied(entry) => {
+                    let seen = &mut nodes.seen[*entry.get()];
+                    if cost >= seen.cost {
+                        continue;
+                    }
+                    seen.cost = cost;
+                    seen.prev = current;
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    let index = nodes.seen.len();
+                    nodes.seen.push(Seen { node: entry.key().clone(), cost, prev: current });
+                    entry.insert(index);
+                    index
+                }
+            };
+            let f = cost + heuristic(&nodes.seen[index].node);
+            open.push(Open { f, g: cost, index });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Small weighted graph: the direct route from a to d is dearer than going round
+    fn links(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3), ('a', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let steps = |node: &char| links(node).into_iter().map(|(n, _)| n);
+        let path = bfs('a', steps, |&n| n == 'd').unwrap();
+        assert_eq!((path.nodes, path.cost), (vec!['a', 'd'], 1));
+        let path = bfs('b', steps, |&n| n == 'd').unwrap();
+        assert_eq!((path.nodes, path.cost), (vec!['b', 'c', 'd'], 2));
+        let path = bfs('d', steps, |&n| n == 'd').unwrap();
+        assert_eq!((path.nodes, path.cost, path.visited), (vec!['d'], 0, 0));
+        assert_eq!(bfs('d', steps, |&n| n == 'a'), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra('a', links, |&n| n == 'd').unwrap();
+        assert_eq!((path.nodes, path.cost), (vec!['a', 'b', 'c', 'd'], 6));
+        assert_eq!(dijkstra('d', links, |&n| n == 'a'), None);
+    }
+
+    #[test]
+    fn test_multi() {
+        let steps = |node: &char| links(node).into_iter().map(|(n, _)| n);
+        let path = bfs_multi(['c', 'b'], steps, |&n| n == 'd').unwrap();
+        assert_eq!((path.source(), path.target(), path.cost), (&'c', &'d', 1));
+        let ends = HashSet::from(['c', 'd']);
+        let path = bfs_multi(['a', 'a'], steps, in_set(&ends)).unwrap();
+        assert_eq!((path.nodes, path.cost), (vec!['a', 'd'], 1));
+        // A start can also be an end
+        let path = dijkstra_multi(['a', 'd'], links, |&n| n == 'd').unwrap();
+        assert_eq!((path.nodes, path.cost), (vec!['d'], 0));
+        let path = dijkstra_multi(['a', 'b'], links, in_set(&ends)).unwrap();
+        assert_eq!((path.source(), path.target(), path.cost), (&'b', &'c', 2));
+        assert_eq!(dijkstra_multi(Vec::new(), links, |_| true), None);
+    }
+
+    #[test]
+    fn test_a_star_grid() {
+        // Open 10x10 grid with a wall at x = 5, except at y = 9
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .filter(|&(x, y)| x != 5 || y == 9)
+                .map(|n| (n, 1))
+        };
+        let end = (9, 0);
+        let distance = |&(x, y): &(i32, i32)| (end.0 - x).abs() + (end.1 - y).abs();
+        let path = a_star((0, 0), neighbours, distance, |&n| n == end).unwrap();
+        assert_eq!(path.cost, 27);
+        assert_eq!(path.nodes.len(), 28);
+        assert!(path.nodes.contains(&(5, 9)));
+        // The heuristic should save some work over plain Dijkstra
+        let plain = dijkstra((0, 0), neighbours, |&n| n == end).unwrap();
+        assert_eq!(plain.cost, 27);
+        assert!(path.visited < plain.visited);
+        let (node, cost) = a_star_cost((0, 0), neighbours, distance, |&n| n == end).unwrap();
+        assert_eq!((node, cost), (end, 27));
+    }
+
+    #[test]
+    fn test_a_star_cheaper_later() {
+        // 'c' is first reached via the dear direct link, then more cheaply through 'b', which
+        // leaves a stale entry for it in the open set
+        let links = |node: &char| match node {
+            'a' => vec![('c', 5), ('b', 1)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let path = dijkstra('a', links, |&n| n == 'd').unwrap();
+        assert_eq!((path.nodes, path.cost, path.visited), (vec!['a', 'b', 'c', 'd'], 3, 3));
+        assert_eq!(a_star_cost('a', links, |_| 0, |&n| n == 'e'), None);
+    }
+}
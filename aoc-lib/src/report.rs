@@ -0,0 +1,395 @@
+use crate::harness::{Answer, SolutionInfo, Unsolved};
+use anyhow::{anyhow, bail, Error, Result};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Results of running a solution
+#[derive(Debug, Clone)]
+pub struct RunReport {
+    pub info: SolutionInfo,
+    /// Time taken to parse input
+    pub parse: Duration,
+    /// Error from parsing input, in which case no parts were run
+    pub error: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+/// Outcome and time taken for a single part
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub outcome: PartOutcome,
+    pub duration: Duration,
+}
+
+/// What came of solving a part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartOutcome {
+    Answer(Answer),
+    Error(String),
+    /// Exceeded the given time limit
+    Timeout(Duration),
+    /// Part has no answer, and isn't expected to
+    Unsolved(Unsolved),
+}
+
+impl RunReport {
+    /// Answer for given part, if it was solved
+    pub fn answer(&self, part: u8) -> Option<&Answer> {
+        self.parts.iter().find(|x| x.part == part).and_then(|x| match &x.outcome {
+            PartOutcome::Answer(answer) => Some(answer),
+            _ => None,
+        })
+    }
+
+    /// First error encountered while running, if any, for callers that want a plain Result.
+    /// Unsolved parts don't count as errors.
+    pub fn to_result(&self) -> Result<()> {
+        if let Some(error) = &self.error {
+            bail!("Failed to parse input: {}", error);
+        }
+        for part in self.parts.iter() {
+            match &part.outcome {
+                PartOutcome::Answer(_) | PartOutcome::Unsolved(_) => (),
+                PartOutcome::Error(error) => bail!("Part {} failed: {}", part.part, error),
+                PartOutcome::Timeout(_) => bail!("Part {} {}", part.part, part.outcome),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for PartOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartOutcome::Answer(answer) => write!(f, "{}", answer),
+            PartOutcome::Error(error) => write!(f, "Error: {}", error),
+            PartOutcome::Timeout(limit) => write!(f, "TIMEOUT (limit {:?})", limit),
+            PartOutcome::Unsolved(unsolved) => write!(f, "UNSOLVED ({})", unsolved),
+        }
+    }
+}
+
+/// Turns run reports into output text
+pub trait Renderer {
+    /// Text to output before any reports
+    fn header(&self) -> String {
+        String::new()
+    }
+
+    /// Text to output for a single report
+    fn render(&self, report: &RunReport) -> String;
+}
+
+/// Supported output formats for run reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Tsv,
+    Markdown,
+}
+
+impl OutputFormat {
+    /// Renderer for this format
+    pub fn renderer(&self) -> Box<dyn Renderer> {
+        match self {
+            OutputFormat::Text => Box::new(TextRenderer),
+            OutputFormat::Json => Box::new(JsonRenderer),
+            OutputFormat::Tsv => Box::new(TsvRenderer),
+            OutputFormat::Markdown => Box::new(MarkdownRenderer),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "tsv" => Ok(OutputFormat::Tsv),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            _ => Err(anyhow!("Unrecognised output format: {}", s)),
+        }
+    }
+}
+
+/// Human-readable text, as output by run_solution
+pub struct TextRenderer;
+
+impl TextRenderer {
+    /// Text for the latest step of a report that's still being run (see
+    /// DynSolution::run_with_progress): the header and parse time once input is parsed, then each
+    /// part as it finishes. Concatenated, this matches render.
+    pub fn render_progress(&self, report: &RunReport) -> String {
+        match report.parts.last() {
+            Some(part) => self.render_part(part),
+            None => self.render_parse(report),
+        }
+    }
+
+    fn render_parse(&self, report: &RunReport) -> String {
+        let info = &report.info;
+        let mut result =
+            format!("\n--- [{}] Day {}: {} ---\n", info.year(), info.day(), info.title());
+        result += &format!("\nParse input\n[{:?}]\n", report.parse);
+        if let Some(error) = &report.error {
+            result += &format!("Error: {}\n", error);
+        }
+        result
+    }
+
+    fn render_part(&self, part: &PartReport) -> String {
+        format!("\nPart {}:\n{}\n[{:?}]\n", part.part, part.outcome, part.duration)
+    }
+}
+
+impl Renderer for TextRenderer {
+    fn render(&self, report: &RunReport) -> String {
+        let mut result = self.render_parse(report);
+        for part in report.parts.iter() {
+            result += &self.render_part(part);
+        }
+        result
+    }
+}
+
+/// One JSON object per line
+pub struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, report: &RunReport) -> String {
+        let info = &report.info;
+        let parts: Vec<String> = report
+            .parts
+            .iter()
+            .map(|part| {
+                let null = || "null".to_string();
+                let (status, answer, error) = match &part.outcome {
+                    PartOutcome::Answer(Answer::Int(x)) => ("ok", x.to_string(), null()),
+                    PartOutcome::Answer(answer) => ("ok", json_string(&answer.to_string()), null()),
+                    PartOutcome::Error(error) => ("error", null(), json_string(error)),
+                    PartOutcome::Timeout(_) => {
+                        ("timeout", null(), json_string(&part.outcome.to_string()))
+                    }
+                    PartOutcome::Unsolved(unsolved) => {
+                        ("unsolved", null(), json_string(&unsolved.to_string()))
+                    }
+                };
+                format!(
+                    "{{\"part\":{},\"status\":\"{}\",\"answer\":{},\"error\":{},\
+                    \"duration_ns\":{}}}",
+                    part.part,
+                    status,
+                    answer,
+                    error,
+                    part.duration.as_nanos()
+                )
+            })
+            .collect();
+        format!(
+            "{{\"year\":{},\"day\":{},\"title\":{},\"parse_ns\":{},\"error\":{},\"parts\":[{}]}}\n",
+            info.year(),
+            info.day(),
+            json_string(info.title()),
+            report.parse.as_nanos(),
+            report.error.as_deref().map(json_string).unwrap_or_else(|| "null".to_string()),
+            parts.join(",")
+        )
+    }
+}
+
+/// Quote and escape text as a JSON string
+fn json_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Columns shared by the tabular formats: one row per part
+const TABLE_COLUMNS: [&str; 7] = ["year", "day", "title", "part", "answer", "time", "error"];
+
+/// Table cells for each part of a report (or a single row for a parse error)
+fn table_rows(report: &RunReport) -> Vec<[String; 7]> {
+    let info = &report.info;
+    let row = |part: String, answer: &str, time: Duration, error: &str| {
+        [
+            info.year().to_string(),
+            info.day().to_string(),
+            info.title().to_string(),
+            part,
+            answer.trim().to_string(),
+            format!("{:.2?}", time),
+            error.to_string(),
+        ]
+    };
+    if let Some(error) = &report.error {
+        return vec![row("parse".to_string(), "", report.parse, error)];
+    }
+    report
+        .parts
+        .iter()
+        .map(|part| match &part.outcome {
+            PartOutcome::Answer(answer) => {
+                row(part.part.to_string(), &answer.to_string(), part.duration, "")
+            }
+            PartOutcome::Error(error) => row(part.part.to_string(), "", part.duration, error),
+            PartOutcome::Timeout(_) | PartOutcome::Unsolved(_) => {
+                row(part.part.to_string(), "", part.duration, &part.outcome.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Tab-separated values, with a header row
+pub struct TsvRenderer;
+
+impl Renderer for TsvRenderer {
+    fn header(&self) -> String {
+        TABLE_COLUMNS.join("\t") + "\n"
+    }
+
+    fn render(&self, report: &RunReport) -> String {
+        table_rows(report)
+            .iter()
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .map(|x| x.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n"))
+                    .collect();
+                cells.join("\t") + "\n"
+            })
+            .collect()
+    }
+}
+
+/// Markdown summary table, e.g. for the README
+pub struct MarkdownRenderer;
+
+impl Renderer for MarkdownRenderer {
+    fn header(&self) -> String {
+        format!("| {} |\n|{}\n", TABLE_COLUMNS.join(" | "), "---|".repeat(TABLE_COLUMNS.len()))
+    }
+
+    fn render(&self, report: &RunReport) -> String {
+        table_rows(report)
+            .iter()
+            .map(|row| {
+                let cells: Vec<String> =
+                    row.iter().map(|x| x.replace('|', "\\|").replace('\n', "<br>")).collect();
+                format!("| {} |\n", cells.join(" | "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> RunReport {
+        RunReport {
+            info: SolutionInfo::new("Cathode-Ray Tube", 2022, 10),
+            parse: Duration::from_micros(250),
+            error: None,
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    outcome: PartOutcome::Answer(Answer::Int(13140)),
+                    duration: Duration::from_micros(130),
+                },
+                PartReport {
+                    part: 2,
+                    outcome: PartOutcome::Error("No \"CRT\"".to_string()),
+                    duration: Duration::from_micros(20),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_text() {
+        let text = TextRenderer.render(&report());
+        assert!(text.starts_with("\n--- [2022] Day 10: Cathode-Ray Tube ---\n"));
+        assert!(text.contains("\nPart 1:\n13140\n[130µs]\n"));
+        assert!(text.contains("\nPart 2:\nError: No \"CRT\"\n"));
+        let mut report = report();
+        report.parts[1].outcome = PartOutcome::Unsolved(Unsolved::NotImplemented);
+        assert!(TextRenderer.render(&report).contains("\nPart 2:\nUNSOLVED (not solved yet)\n"));
+    }
+
+    #[test]
+    fn test_text_progress() {
+        let full = report();
+        let mut report = full.clone();
+        report.parts.clear();
+        let mut text = TextRenderer.render_progress(&report);
+        for part in full.parts.iter() {
+            report.parts.push(part.clone());
+            text += &TextRenderer.render_progress(&report);
+        }
+        assert_eq!(text, TextRenderer.render(&full));
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            JsonRenderer.render(&report()),
+            "{\"year\":2022,\"day\":10,\"title\":\"Cathode-Ray Tube\",\"parse_ns\":250000,\
+            \"error\":null,\"parts\":[\
+            {\"part\":1,\"status\":\"ok\",\"answer\":13140,\"error\":null,\
+            \"duration_ns\":130000},\
+            {\"part\":2,\"status\":\"error\",\"answer\":null,\"error\":\"No \\\"CRT\\\"\",\
+            \"duration_ns\":20000}]}\n"
+        );
+        assert_eq!(json_string("a\nb\\c"), "\"a\\nb\\\\c\"");
+    }
+
+    #[test]
+    fn test_tsv() {
+        assert_eq!(
+            TsvRenderer.render(&report()),
+            "2022\t10\tCathode-Ray Tube\t1\t13140\t130.00µs\t\n\
+            2022\t10\tCathode-Ray Tube\t2\t\t20.00µs\tNo \"CRT\"\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        let mut report = report();
+        report.parts[0].outcome = PartOutcome::Answer(Answer::Art("#.|\n.#.".to_string()));
+        let header = MarkdownRenderer.header();
+        assert!(header.starts_with("| year | day | title | part | answer | time | error |\n|---|"));
+        assert!(MarkdownRenderer
+            .render(&report)
+            .starts_with("| 2022 | 10 | Cathode-Ray Tube | 1 | #.\\|<br>.#. | 130.00µs |  |\n"));
+    }
+
+    #[test]
+    fn test_to_result() {
+        let mut report = report();
+        assert!(report.to_result().is_err());
+        report.parts[1].outcome = PartOutcome::Timeout(Duration::from_secs(1));
+        assert_eq!(report.to_result().unwrap_err().to_string(), "Part 2 TIMEOUT (limit 1s)");
+        report.parts[1].outcome = PartOutcome::Unsolved(Unsolved::NotApplicable);
+        assert!(report.to_result().is_ok());
+        report.parts.pop();
+        assert!(report.to_result().is_ok());
+        assert_eq!(report.answer(1), Some(&Answer::Int(13140)));
+        assert_eq!(report.answer(2), None);
+    }
+}
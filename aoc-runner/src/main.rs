@@ -0,0 +1,412 @@
+use anyhow::{anyhow, bail, Result};
+use aoc_lib::answers::{Answers, Verdict};
+use aoc_lib::bench::{BenchBudget, BenchOptions};
+use aoc_lib::cases::{run_cases, Cases};
+use aoc_lib::common::parse_str;
+use aoc_lib::harness::{
+    BoxedSolution, DynSolution, FileResource, PathResource, Resource, RunLimits, StdinResource,
+};
+use aoc_lib::history::{self, BenchRecord, DEFAULT_RESULTS_DIR};
+use aoc_lib::input;
+use aoc_lib::parallel::{default_threads, map_parallel};
+use aoc_lib::report::{OutputFormat, RunReport, TextRenderer};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const USAGE: &str = "Usage:
+    aoc list                List all available solutions
+    aoc run all [options]   Run every solution
+    aoc run <year> [day] [options]
+                            Run all solutions for a year, or a single day
+    aoc cases <all | year [day]>
+                            Run every named input listed in dayNN.cases.toml, checking answers
+    aoc bench <all | year [day]> [options]
+                            Benchmark solutions, timing each step over repeated runs
+    aoc compare [options]   Compare latest benchmark results against a baseline
+
+Global options:
+    --input-dir <dir>       Where to find puzzle inputs (as <dir>/<year>/dayNN.), ahead of
+                            $AOC_INPUT_DIR and input_dir in aoc.conf
+
+Run options:
+    --format <format>       Output format: text (default), json, tsv or markdown
+    --check                 Verify answers against recorded dayNN.answers
+    --record                As --check, but also record answers for days that have none
+    --parallel              Run days concurrently, one thread per CPU (timings are less reliable)
+    --jobs <n>              As --parallel, but with n threads
+    --timeout <secs>        Time limit for each part; slower parts are reported as TIMEOUT
+    --input <file>          Run a single day on given input file instead (- for stdin)
+
+Bench options:
+    --runs <n>              Number of timed runs per step (default 10)
+    --time <secs>           Time budget per step, instead of a fixed number of runs
+    --warmup <n>            Untimed runs per step before timing starts (default 1)
+    --no-save               Don't append results to the benchmark history
+    --results-dir <dir>     Where benchmark history is kept (default bench-results)
+
+Compare options:
+    --baseline <commit>     Commit to compare against (default: the previous benchmarked commit)
+    --threshold <percent>   Slowdown that counts as a regression (default 10)
+    --results-dir <dir>     Where benchmark history is kept (default bench-results)";
+
+/// Which solutions to run
+enum Selection {
+    All,
+    Year(u32),
+    Day(u32, u8),
+}
+
+impl Selection {
+    fn parse(args: &[&String]) -> Result<Selection> {
+        match args {
+            [all] if *all == "all" => Ok(Selection::All),
+            [year] => Ok(Selection::Year(parse_str(year)?)),
+            [year, day] => Ok(Selection::Day(parse_str(year)?, parse_str(day)?)),
+            _ => bail!("Expected 'all', <year> or <year> <day>\n\n{}", USAGE),
+        }
+    }
+
+    fn matches(&self, solution: &dyn DynSolution) -> bool {
+        let info = solution.info();
+        match *self {
+            Selection::All => true,
+            Selection::Year(year) => info.year() == year,
+            Selection::Day(year, day) => info.year() == year && info.day() == day,
+        }
+    }
+}
+
+/// What to do with answers once a solution has run
+#[derive(PartialEq, Eq)]
+enum AnswerMode {
+    Ignore,
+    Check,
+    Record,
+}
+
+/// Options for the run command
+struct RunOptions {
+    selection: Selection,
+    answers: AnswerMode,
+    format: OutputFormat,
+    /// Number of threads, if running in parallel
+    jobs: Option<usize>,
+    limits: RunLimits,
+    /// Input file to use instead of the day's puzzle input, or "-" for stdin
+    input: Option<String>,
+}
+
+impl RunOptions {
+    fn parse(args: &[String]) -> Result<RunOptions> {
+        let mut answers = AnswerMode::Ignore;
+        let mut format = OutputFormat::Text;
+        let mut jobs = None;
+        let mut limits = RunLimits::default();
+        let mut input = None;
+        let mut positional = Vec::new();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--format" => format = parse_str(option_value(&mut iter, arg)?)?,
+                "--parallel" => jobs = Some(default_threads()),
+                "--jobs" => jobs = Some(parse_str(option_value(&mut iter, arg)?)?),
+                "--timeout" => {
+                    limits.part_timeout = Some(parse_secs(option_value(&mut iter, arg)?, arg)?)
+                }
+                // --record implies --check, whatever order they're given in
+                "--check" if answers == AnswerMode::Ignore => answers = AnswerMode::Check,
+                "--check" => (),
+                "--record" => answers = AnswerMode::Record,
+                "--input" => input = Some(option_value(&mut iter, arg)?.clone()),
+                x if x.starts_with("--") => bail!("Unrecognised option: {}\n\n{}", x, USAGE),
+                _ => positional.push(arg),
+            }
+        }
+        let selection = Selection::parse(&positional)?;
+        if input.is_some() {
+            if !matches!(selection, Selection::Day(..)) {
+                bail!("--input needs a single day to run");
+            }
+            if answers != AnswerMode::Ignore {
+                bail!("--input can't be used with --check or --record");
+            }
+        }
+        Ok(RunOptions { selection, answers, format, jobs, limits, input })
+    }
+}
+
+/// Options for the bench command
+struct BenchCmdOptions {
+    selection: Selection,
+    bench: BenchOptions,
+    save: bool,
+    results_dir: PathBuf,
+}
+
+impl BenchCmdOptions {
+    fn parse(args: &[String]) -> Result<BenchCmdOptions> {
+        let mut bench = BenchOptions::default();
+        let mut save = true;
+        let mut results_dir = PathBuf::from(DEFAULT_RESULTS_DIR);
+        let mut positional = Vec::new();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--runs" => {
+                    bench.budget = BenchBudget::Runs(parse_str(option_value(&mut iter, arg)?)?)
+                }
+                "--time" => {
+                    bench.budget =
+                        BenchBudget::Time(parse_secs(option_value(&mut iter, arg)?, arg)?)
+                }
+                "--warmup" => bench.warmup = parse_str(option_value(&mut iter, arg)?)?,
+                "--no-save" => save = false,
+                "--results-dir" => results_dir = PathBuf::from(option_value(&mut iter, arg)?),
+                x if x.starts_with("--") => bail!("Unrecognised option: {}\n\n{}", x, USAGE),
+                _ => positional.push(arg),
+            }
+        }
+        Ok(BenchCmdOptions { selection: Selection::parse(&positional)?, bench, save, results_dir })
+    }
+}
+
+/// Options for the compare command
+struct CompareOptions {
+    baseline: Option<String>,
+    threshold: f64,
+    results_dir: PathBuf,
+}
+
+impl CompareOptions {
+    fn parse(args: &[String]) -> Result<CompareOptions> {
+        let mut options = CompareOptions {
+            baseline: None,
+            threshold: 10.0,
+            results_dir: PathBuf::from(DEFAULT_RESULTS_DIR),
+        };
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--baseline" => options.baseline = Some(option_value(&mut iter, arg)?.clone()),
+                "--threshold" => options.threshold = parse_str(option_value(&mut iter, arg)?)?,
+                "--results-dir" => {
+                    options.results_dir = PathBuf::from(option_value(&mut iter, arg)?)
+                }
+                x => bail!("Unrecognised argument: {}\n\n{}", x, USAGE),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Value following an option that requires one
+fn option_value<'a>(
+    iter: &mut impl Iterator<Item = &'a String>,
+    option: &str,
+) -> Result<&'a String> {
+    iter.next().ok_or_else(|| anyhow!("Missing value for {}\n\n{}", option, USAGE))
+}
+
+/// Duration from an option value in (possibly fractional) seconds
+fn parse_secs(value: &str, option: &str) -> Result<Duration> {
+    let secs: f64 = parse_str(value)?;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(duration) => Ok(duration),
+        Err(_) => bail!("Invalid value for {}: {} (expected seconds, at least 0)", option, value),
+    }
+}
+
+/// Every registered solution, across all years
+fn registry() -> Vec<BoxedSolution> {
+    let mut result = aoc_2021::solutions();
+    result.extend(aoc_2022::solutions());
+    result
+}
+
+/// Registered solutions matching selection
+fn select(selection: &Selection) -> Result<Vec<BoxedSolution>> {
+    let selected: Vec<_> =
+        registry().into_iter().filter(|x| selection.matches(x.as_ref())).collect();
+    if selected.is_empty() {
+        bail!("No solutions match selection");
+    }
+    Ok(selected)
+}
+
+fn list() {
+    for solution in registry() {
+        let info = solution.info();
+        println!("[{}] Day {:02}: {}", info.year(), info.day(), info.title());
+    }
+}
+
+/// Check (and possibly record) answers for a run. Returns number of mismatches.
+fn check_answers(report: &RunReport, mode: &AnswerMode, out: &mut dyn Write) -> Result<usize> {
+    let (year, day) = (report.info.year(), report.info.day());
+    let known = match Answers::load(year, day)? {
+        Some(known) => known,
+        None if *mode == AnswerMode::Record => {
+            let path = Answers::from_report(report).save(year, day)?;
+            writeln!(out, "\nRecorded answers to {}", path.display())?;
+            return Ok(0);
+        }
+        None => Answers::default(),
+    };
+    let mut failures = 0;
+    writeln!(out)?;
+    for (part, verdict) in known.check_report(report) {
+        if matches!(verdict, Verdict::Fail { .. }) {
+            failures += 1;
+        }
+        writeln!(out, "Part {}: {}", part, verdict)?;
+    }
+    Ok(failures)
+}
+
+fn run(options: RunOptions) -> Result<()> {
+    let renderer = options.format.renderer();
+    // Keep machine-readable output clean by sending anything else to stderr
+    let mut out: Box<dyn Write> = match options.format {
+        OutputFormat::Text => Box::new(std::io::stdout()),
+        _ => Box::new(std::io::stderr()),
+    };
+    let (mut errors, mut failures) = (0, 0);
+    print!("{}", renderer.header());
+    let selected = select(&options.selection)?;
+    let run_one = |solution: &BoxedSolution, progress: &mut dyn FnMut(&RunReport)| {
+        let info = solution.info();
+        let resource: Box<dyn Resource> = match options.input.as_deref() {
+            Some("-") => Box::new(StdinResource::new()),
+            Some(path) => Box::new(PathResource::new(path)),
+            None => Box::new(FileResource::new("", info.year(), info.day())),
+        };
+        solution.run_with_progress(resource.as_ref(), &options.limits, progress)
+    };
+    // In parallel, everything has to finish before reports can be output in order. Otherwise,
+    // output each report as soon as it's ready: as text, each part as soon as it's solved.
+    let mut parallel_reports = match options.jobs {
+        Some(jobs) => map_parallel(&selected, jobs, |x| run_one(x, &mut |_| ()))
+            .into_iter()
+            .map(Some)
+            .collect(),
+        None => vec![None; selected.len()],
+    };
+    for (solution, report) in selected.iter().zip(parallel_reports.iter_mut()) {
+        let report = match report.take() {
+            None if options.format == OutputFormat::Text => {
+                run_one(solution, &mut |report| print!("{}", TextRenderer.render_progress(report)))
+            }
+            report => {
+                let report = report.unwrap_or_else(|| run_one(solution, &mut |_| ()));
+                print!("{}", renderer.render(&report));
+                report
+            }
+        };
+        if report.to_result().is_err() {
+            errors += 1;
+        }
+        if options.answers != AnswerMode::Ignore {
+            failures += check_answers(&report, &options.answers, &mut out)?;
+        }
+    }
+    if errors > 0 || failures > 0 {
+        bail!("{} solution(s) failed, {} answer(s) did not match", errors, failures);
+    }
+    Ok(())
+}
+
+fn cases(selection: Selection) -> Result<()> {
+    let (mut total, mut failed) = (0, 0);
+    for solution in select(&selection)? {
+        let info = solution.info();
+        let cases = Cases::load(info.year(), info.day())?;
+        for report in run_cases(solution.as_ref(), &cases, &RunLimits::default())? {
+            println!("{}", report);
+            total += 1;
+            if !report.passed() {
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        bail!("{} of {} case(s) failed", failed, total);
+    }
+    println!("\n{} case(s) passed", total);
+    Ok(())
+}
+
+fn bench(options: BenchCmdOptions) -> Result<()> {
+    let (commit, timestamp) = (history::current_commit(), history::current_timestamp());
+    let mut records = Vec::new();
+    for solution in select(&options.selection)? {
+        let info = solution.info();
+        let resource = FileResource::new("", info.year(), info.day());
+        let report = solution.bench(&resource, &options.bench)?;
+        print!("{}", report);
+        records.extend(BenchRecord::from_report(&report, &commit, timestamp));
+    }
+    if options.save {
+        history::append_history(&options.results_dir, &records)?;
+        println!("\nSaved results for {} to {}", commit, options.results_dir.display());
+    }
+    Ok(())
+}
+
+fn compare(options: CompareOptions) -> Result<()> {
+    let records = history::load_history(&options.results_dir)?;
+    let comparison = history::compare(&records, options.baseline.as_deref(), options.threshold)?;
+    print!("{}", comparison);
+    let regressions = comparison.regressions().len();
+    if regressions > 0 {
+        bail!("{} phase(s) slowed down by more than {}%", regressions, options.threshold);
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(i) = args.iter().position(|x| x == "--input-dir") {
+        let dir = args.get(i + 1).ok_or_else(|| anyhow!("Missing value for --input-dir"))?;
+        input::set_input_dir(Some(PathBuf::from(dir)));
+        args.drain(i..i + 2);
+    }
+    match args.split_first() {
+        Some((cmd, [])) if cmd == "list" => list(),
+        Some((cmd, rest)) if cmd == "run" => run(RunOptions::parse(rest)?)?,
+        Some((cmd, rest)) if cmd == "cases" => {
+            cases(Selection::parse(&rest.iter().collect::<Vec<_>>())?)?
+        }
+        Some((cmd, rest)) if cmd == "bench" => bench(BenchCmdOptions::parse(rest)?)?,
+        Some((cmd, rest)) if cmd == "compare" => compare(CompareOptions::parse(rest)?)?,
+        _ => bail!("{}", USAGE),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_timeout() {
+        let options = RunOptions::parse(&args(&["2022", "1", "--timeout", "1.5"])).unwrap();
+        assert_eq!(options.limits.part_timeout, Some(Duration::from_millis(1500)));
+        for value in ["-1", "NaN", "inf", "x"] {
+            assert!(RunOptions::parse(&args(&["2022", "1", "--timeout", value])).is_err());
+        }
+    }
+
+    #[test]
+    fn test_bench_time() {
+        let options = BenchCmdOptions::parse(&args(&["all", "--time", "2"])).unwrap();
+        assert!(
+            matches!(options.bench.budget, BenchBudget::Time(x) if x == Duration::from_secs(2))
+        );
+        assert!(BenchCmdOptions::parse(&args(&["all", "--time", "-0.5"])).is_err());
+    }
+}